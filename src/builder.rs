@@ -0,0 +1,366 @@
+//! Building statements in code, without writing out the whole AST.
+//!
+//! ```
+//! use logic_rs::builder::{forall, letter, not, pred, term, var};
+//!
+//! // (~A & B)
+//! let statement = not(letter('A')).and(letter('B')).build().unwrap();
+//!
+//! // ∀xL²xm
+//! let x = var('x');
+//! let statement = forall(x.clone(), pred('L', 2, vec![x.into(), term('m').into()]))
+//!     .build()
+//!     .unwrap();
+//! ```
+//!
+//! The statements built are exactly those the parser would produce from the same
+//! statements written as input (e.g. connectives inside quantifiers make
+//! [Formula](../enum.Formula.html)s rather than [Statement](../enum.Statement.html)s),
+//! so they can be compared with parsed statements. Parsed statements can also be turned into an
+//! [Expr](struct.Expr.html) (with `Expr::from`) to build upon them.
+
+use crate::parser::{
+    validate_statement, Degree, Formula, ParseErrorKind, PredicateLetter, SimpleStatementLetter,
+    SingularTerm, Statement, Subscript, Term, ValidationError, Variable,
+};
+
+/// A statement (or, inside a quantifier, a formula) being built.
+/// See the [module documentation](index.html).
+#[derive(Debug, Clone)]
+pub struct Expr(pub(crate) Node);
+
+#[derive(Debug, Clone)]
+pub(crate) enum Node {
+    Simple(SimpleStatementLetter),
+    Predicate(PredicateLetter, Vec<Term>),
+    Identity(Term, Term),
+    Negation(Box<Node>),
+    Conjunction(Box<Node>, Box<Node>),
+    Disjunction(Box<Node>, Box<Node>),
+    Conditional(Box<Node>, Box<Node>),
+    Biconditional(Box<Node>, Box<Node>),
+    Existential(Variable, Box<Node>),
+    Universal(Variable, Box<Node>),
+}
+
+/// Returns the simple statement `letter` (e.g. `A`).
+pub fn letter(letter: char) -> Expr {
+    Expr(Node::Simple(SimpleStatementLetter(letter, Subscript(None))))
+}
+
+/// Returns the variable `letter` (e.g. `x`), to be bound by [forall](fn.forall.html)
+/// or [exists](fn.exists.html) and used as a term.
+pub fn var(letter: char) -> Variable {
+    Variable(letter, Subscript(None))
+}
+
+/// Returns the singular term `letter` (e.g. `a`).
+pub fn term(letter: char) -> SingularTerm {
+    SingularTerm(letter, Subscript(None))
+}
+
+/// Returns the predicate `letter` of degree `degree` applied to `terms` (e.g. `L²xm`).
+///
+/// Whether `degree` matches the number of terms is checked by [build](struct.Expr.html#method.build).
+pub fn pred(letter: char, degree: u64, terms: Vec<Term>) -> Expr {
+    Expr(Node::Predicate(
+        PredicateLetter(letter, Subscript(None), Degree(degree)),
+        terms,
+    ))
+}
+
+/// Returns the identity `lterm = rterm`.
+pub fn eq<L: Into<Term>, R: Into<Term>>(lterm: L, rterm: R) -> Expr {
+    Expr(Node::Identity(lterm.into(), rterm.into()))
+}
+
+/// Returns the negation of `expr`.
+pub fn not(expr: Expr) -> Expr {
+    Expr(Node::Negation(Box::new(expr.0)))
+}
+
+/// Returns `expr` universally quantified over `variable`.
+pub fn forall(variable: Variable, expr: Expr) -> Expr {
+    Expr(Node::Universal(variable, Box::new(expr.0)))
+}
+
+/// Returns `expr` existentially quantified over `variable`.
+pub fn exists(variable: Variable, expr: Expr) -> Expr {
+    Expr(Node::Existential(variable, Box::new(expr.0)))
+}
+
+impl Expr {
+    /// Returns the conjunction of `self` and `rhs`.
+    pub fn and(self, rhs: Expr) -> Expr {
+        Expr(Node::Conjunction(Box::new(self.0), Box::new(rhs.0)))
+    }
+
+    /// Returns the disjunction of `self` and `rhs`.
+    pub fn or(self, rhs: Expr) -> Expr {
+        Expr(Node::Disjunction(Box::new(self.0), Box::new(rhs.0)))
+    }
+
+    /// Returns the conditional with `self` as the antecedent and `rhs` as the consequent.
+    pub fn implies(self, rhs: Expr) -> Expr {
+        Expr(Node::Conditional(Box::new(self.0), Box::new(rhs.0)))
+    }
+
+    /// Returns the biconditional of `self` and `rhs`.
+    pub fn iff(self, rhs: Expr) -> Expr {
+        Expr(Node::Biconditional(Box::new(self.0), Box::new(rhs.0)))
+    }
+
+    /// Builds the statement.
+    ///
+    /// Fails if the statement is semantically invalid, the same way
+    /// [StatementSet::new](../struct.StatementSet.html#method.new) does.
+    pub fn build(self) -> Result<Statement, ValidationError> {
+        let statement = into_statement(self.0)?;
+
+        validate_statement(&statement)?;
+
+        Ok(statement)
+    }
+}
+
+impl From<Statement> for Expr {
+    /// Returns an expression that builds `statement` again, so that statements that were
+    /// parsed can be built upon.
+    fn from(statement: Statement) -> Self {
+        Expr(from_statement(statement))
+    }
+}
+
+fn from_statement(statement: Statement) -> Node {
+    match statement {
+        Statement::Simple(letter) => Node::Simple(letter),
+        Statement::Singular(predicate_letter, terms) => Node::Predicate(
+            predicate_letter,
+            terms.into_iter().map(Term::SingularTerm).collect(),
+        ),
+        Statement::Identity(lterm, rterm) => {
+            Node::Identity(Term::SingularTerm(lterm), Term::SingularTerm(rterm))
+        }
+        Statement::LogicalNegation(rstatement) => {
+            Node::Negation(Box::new(from_statement(*rstatement)))
+        }
+        Statement::LogicalConjunction(lstatement, rstatement) => Node::Conjunction(
+            Box::new(from_statement(*lstatement)),
+            Box::new(from_statement(*rstatement)),
+        ),
+        Statement::LogicalDisjunction(lstatement, rstatement) => Node::Disjunction(
+            Box::new(from_statement(*lstatement)),
+            Box::new(from_statement(*rstatement)),
+        ),
+        Statement::LogicalConditional(lstatement, rstatement) => Node::Conditional(
+            Box::new(from_statement(*lstatement)),
+            Box::new(from_statement(*rstatement)),
+        ),
+        Statement::LogicalBiconditional(lstatement, rstatement) => Node::Biconditional(
+            Box::new(from_statement(*lstatement)),
+            Box::new(from_statement(*rstatement)),
+        ),
+        Statement::Existential(variable, formula) => {
+            Node::Existential(variable, Box::new(from_formula(*formula)))
+        }
+        Statement::Universal(variable, formula) => {
+            Node::Universal(variable, Box::new(from_formula(*formula)))
+        }
+    }
+}
+
+fn from_formula(formula: Formula) -> Node {
+    match formula {
+        Formula::Statement(statement) => from_statement(*statement),
+        Formula::Predicate(predicate_letter, terms) => Node::Predicate(predicate_letter, terms),
+        Formula::Identity(lterm, rterm) => Node::Identity(lterm, rterm),
+        Formula::Negation(rformula) => Node::Negation(Box::new(from_formula(*rformula))),
+        Formula::Conjunction(lformula, rformula) => Node::Conjunction(
+            Box::new(from_formula(*lformula)),
+            Box::new(from_formula(*rformula)),
+        ),
+        Formula::Disjunction(lformula, rformula) => Node::Disjunction(
+            Box::new(from_formula(*lformula)),
+            Box::new(from_formula(*rformula)),
+        ),
+        Formula::Conditional(lformula, rformula) => Node::Conditional(
+            Box::new(from_formula(*lformula)),
+            Box::new(from_formula(*rformula)),
+        ),
+        Formula::Biconditional(lformula, rformula) => Node::Biconditional(
+            Box::new(from_formula(*lformula)),
+            Box::new(from_formula(*rformula)),
+        ),
+    }
+}
+
+// Outside of any quantifier, only statements can be built, so any variable is out of scope
+pub(crate) fn into_statement(node: Node) -> Result<Statement, ValidationError> {
+    let statement = match node {
+        Node::Simple(letter) => Statement::Simple(letter),
+        Node::Predicate(predicate_letter, terms) => {
+            Statement::Singular(predicate_letter, into_singular_terms(terms)?)
+        }
+        Node::Identity(lterm, rterm) => {
+            let mut terms = into_singular_terms(vec![lterm, rterm])?;
+            let rterm = terms.pop().unwrap();
+            let lterm = terms.pop().unwrap();
+
+            Statement::Identity(lterm, rterm)
+        }
+        Node::Negation(rnode) => Statement::LogicalNegation(Box::new(into_statement(*rnode)?)),
+        Node::Conjunction(lnode, rnode) => Statement::LogicalConjunction(
+            Box::new(into_statement(*lnode)?),
+            Box::new(into_statement(*rnode)?),
+        ),
+        Node::Disjunction(lnode, rnode) => Statement::LogicalDisjunction(
+            Box::new(into_statement(*lnode)?),
+            Box::new(into_statement(*rnode)?),
+        ),
+        Node::Conditional(lnode, rnode) => Statement::LogicalConditional(
+            Box::new(into_statement(*lnode)?),
+            Box::new(into_statement(*rnode)?),
+        ),
+        Node::Biconditional(lnode, rnode) => Statement::LogicalBiconditional(
+            Box::new(into_statement(*lnode)?),
+            Box::new(into_statement(*rnode)?),
+        ),
+        Node::Existential(variable, node) => {
+            Statement::Existential(variable, Box::new(into_formula(*node)))
+        }
+        Node::Universal(variable, node) => {
+            Statement::Universal(variable, Box::new(into_formula(*node)))
+        }
+    };
+
+    Ok(statement)
+}
+
+// Inside a quantifier, the parser only makes statements out of what can't be a formula,
+// i.e. simple statements, predicates and identities without variables, and quantified statements
+pub(crate) fn into_formula(node: Node) -> Formula {
+    match node {
+        Node::Simple(letter) => Formula::Statement(Box::new(Statement::Simple(letter))),
+        Node::Predicate(predicate_letter, terms) => match into_singular_terms(terms.clone()) {
+            Ok(singular_terms) => Formula::Statement(Box::new(Statement::Singular(
+                predicate_letter,
+                singular_terms,
+            ))),
+            Err(_) => Formula::Predicate(predicate_letter, terms),
+        },
+        Node::Identity(lterm, rterm) => match (lterm, rterm) {
+            (Term::SingularTerm(lterm), Term::SingularTerm(rterm)) => {
+                Formula::Statement(Box::new(Statement::Identity(lterm, rterm)))
+            }
+            (lterm, rterm) => Formula::Identity(lterm, rterm),
+        },
+        Node::Negation(rnode) => Formula::Negation(Box::new(into_formula(*rnode))),
+        Node::Conjunction(lnode, rnode) => Formula::Conjunction(
+            Box::new(into_formula(*lnode)),
+            Box::new(into_formula(*rnode)),
+        ),
+        Node::Disjunction(lnode, rnode) => Formula::Disjunction(
+            Box::new(into_formula(*lnode)),
+            Box::new(into_formula(*rnode)),
+        ),
+        Node::Conditional(lnode, rnode) => Formula::Conditional(
+            Box::new(into_formula(*lnode)),
+            Box::new(into_formula(*rnode)),
+        ),
+        Node::Biconditional(lnode, rnode) => Formula::Biconditional(
+            Box::new(into_formula(*lnode)),
+            Box::new(into_formula(*rnode)),
+        ),
+        Node::Existential(variable, node) => Formula::Statement(Box::new(
+            Statement::Existential(variable, Box::new(into_formula(*node))),
+        )),
+        Node::Universal(variable, node) => Formula::Statement(Box::new(Statement::Universal(
+            variable,
+            Box::new(into_formula(*node)),
+        ))),
+    }
+}
+
+fn into_singular_terms(terms: Vec<Term>) -> Result<Vec<SingularTerm>, ValidationError> {
+    terms
+        .into_iter()
+        .map(|x| match x {
+            Term::SingularTerm(singular_term) => Ok(singular_term),
+            Term::Variable(variable) => Err(ValidationError::Parse(
+                ParseErrorKind::VariableOutOfScope(variable),
+            )),
+            term @ Term::Function(_, _) => Err(ValidationError::FunctionTermNotAllowed(term)),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_statement;
+
+    #[test]
+    fn builds_same_statements_as_parser() {
+        let x = var('x');
+        let y = var('y');
+
+        let pairs = vec![
+            (not(letter('A')).and(letter('B')), "(~A & B)"),
+            (
+                letter('A').or(letter('B')).implies(letter('C').iff(letter('D'))),
+                "((A ∨ B) ⊃ (C ≡ D))",
+            ),
+            (
+                forall(x.clone(), pred('L', 2, vec![x.clone().into(), term('m').into()])),
+                "∀xL²xm",
+            ),
+            (not(eq(term('a'), term('b'))), "~a = b"),
+            (
+                forall(
+                    x.clone(),
+                    pred('A', 1, vec![x.clone().into()])
+                        .and(not(letter('B')))
+                        .implies(exists(
+                            y.clone(),
+                            eq(x.clone(), y.clone()).or(pred('F', 1, vec![term('a').into()])),
+                        )),
+                ),
+                "∀x((A¹x & ~B) ⊃ ∃y(x = y ∨ F¹a))",
+            ),
+        ];
+
+        for (expr, input) in pairs {
+            let statement = parse_statement(input);
+
+            assert_eq!(expr.build().unwrap(), statement);
+            assert_eq!(Expr::from(statement.clone()).build().unwrap(), statement);
+        }
+    }
+
+    #[test]
+    fn validates_degrees_and_scope() {
+        let x = var('x');
+
+        match pred('F', 2, vec![term('a').into()]).build() {
+            Err(ValidationError::Parse(ParseErrorKind::DegreeMismatch { terms: 1, .. })) => {}
+            _ => assert!(false),
+        }
+
+        assert_eq!(
+            pred('F', 1, vec![x.clone().into()]).build().unwrap_err(),
+            ValidationError::Parse(ParseErrorKind::VariableOutOfScope(x.clone()))
+        );
+
+        assert_eq!(
+            exists(var('y'), eq(x.clone(), term('a'))).build().unwrap_err(),
+            ValidationError::Parse(ParseErrorKind::VariableOutOfScope(x.clone()))
+        );
+
+        assert_eq!(
+            exists(x.clone(), forall(x.clone(), pred('F', 1, vec![x.clone().into()])))
+                .build()
+                .unwrap_err(),
+            ValidationError::Parse(ParseErrorKind::VariableAlreadyBound(x))
+        );
+    }
+}
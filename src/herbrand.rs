@@ -0,0 +1,323 @@
+//! The Herbrand universe and the ground instances of sets of statements.
+//!
+//! The Herbrand universe of a set of statements is every term that can be made out of
+//! the singular terms and function symbols of its [Skolemization](../normal_form/fn.skolemize.html)
+//! (or of just `a`, if there are no singular terms). A ground instance of a statement of the
+//! set is the formula that follows the universal quantifiers of its Skolemization, with the
+//! variables they bind replaced by terms of the Herbrand universe.
+//!
+//! By Herbrand's theorem, a set of statements is inconsistent if and only if some finite set
+//! of its ground instances is inconsistent as a matter of truth-functional logic alone. This
+//! is what the truth tree method relies on, too: instantiating a universal statement to every
+//! singular term on a branch, and an existential statement to a new singular term, is finding
+//! the ground instances of the statements on that branch, one at a time. Since there are no
+//! function terms in truth trees, the new singular terms stand in for Skolem functions.
+//!
+//! Both are infinite as soon as there is some function symbol, so they are only enumerated
+//! up to some depth, i.e. the number of function symbols nested within each other.
+//!
+//! ```
+//! use logic_rs::herbrand::{ground_instances, herbrand_universe};
+//! use logic_rs::{parse_input, InputKind};
+//!
+//! match parse_input("{F¹a, ∀x∃yL²xy}") {
+//!     Ok(InputKind::StatementSet(set)) => {
+//!         let terms = herbrand_universe(set.statements(), 2)
+//!             .map(|x| x.to_string())
+//!             .collect::<Vec<_>>();
+//!
+//!         assert_eq!(terms, vec!["a", "f(a)", "f(f(a))"]);
+//!
+//!         let instances = ground_instances(set.statements(), 1)
+//!             .map(|x| x.to_string())
+//!             .collect::<Vec<_>>();
+//!
+//!         assert_eq!(instances, vec!["F¹a", "L²af(a)", "L²f(a)f(f(a))"]);
+//!     }
+//!     _ => assert!(false),
+//! }
+//! ```
+
+use crate::builder::{into_formula, Expr, Node};
+use crate::normal_form::{replace_variable, skolemize};
+use crate::parser::{Formula, FunctionSymbol, SingularTerm, Statement, Term, Variable};
+use crate::validity::first_unused_in_singular_term_stack;
+use crate::visit::{visit_term, Visit};
+
+/// An iterator over the Herbrand universe of a set of statements, up to some depth,
+/// as returned by [herbrand_universe](fn.herbrand_universe.html).
+#[derive(Debug, Clone)]
+pub struct HerbrandUniverse {
+    function_symbols: Vec<(FunctionSymbol, usize)>,
+    max_depth: usize,
+    depth: usize,
+    // Every term up to the current depth, in order
+    terms: Vec<Term>,
+    // Index of the first term of the current depth
+    depth_start: usize,
+    next: usize,
+}
+
+/// An iterator over the ground instances of a set of statements, up to some depth,
+/// as returned by [ground_instances](fn.ground_instances.html).
+#[derive(Debug, Clone)]
+pub struct GroundInstances {
+    // The variables bound by the universal quantifiers of each Skolemized statement,
+    // and the formula that follows them
+    statements: Vec<(Vec<Variable>, Node)>,
+    universe: Vec<Term>,
+    statement: usize,
+    // Which term of the universe each variable of the current statement is replaced with
+    assignment: Option<Vec<usize>>,
+}
+
+/// Returns an iterator over the terms of the Herbrand universe of `statements` with up to
+/// `depth` function symbols nested within each other, by increasing depth.
+///
+/// Terms of the same depth are in order of appearance of their function symbols in the
+/// Skolemization of `statements`, and then of the terms they are applied to.
+pub fn herbrand_universe(statements: &[Statement], depth: usize) -> HerbrandUniverse {
+    let mut signature = Signature::default();
+
+    for statement in skolemize(statements) {
+        signature.visit_statement(&statement);
+    }
+
+    if signature.singular_terms.is_empty() {
+        signature
+            .singular_terms
+            .push(first_unused_in_singular_term_stack(&[]));
+    }
+
+    HerbrandUniverse {
+        function_symbols: signature.function_symbols,
+        max_depth: depth,
+        depth: 0,
+        terms: signature
+            .singular_terms
+            .into_iter()
+            .map(Term::SingularTerm)
+            .collect(),
+        depth_start: 0,
+        next: 0,
+    }
+}
+
+/// Returns an iterator over the ground instances of `statements`, made out of the terms
+/// of their Herbrand universe with up to `depth` function symbols nested within each other.
+///
+/// The ground instances of each statement are in order of the statements, and then of the
+/// terms the variables are replaced with, as given by [herbrand_universe](fn.herbrand_universe.html)
+/// (with the variable bound by the outermost quantifier changing the slowest).
+pub fn ground_instances(statements: &[Statement], depth: usize) -> GroundInstances {
+    GroundInstances {
+        statements: skolemize(statements)
+            .into_iter()
+            .map(|x| {
+                let mut variables = vec![];
+                let mut node = Expr::from(x).0;
+
+                while let Node::Universal(variable, rnode) = node {
+                    variables.push(variable);
+                    node = *rnode;
+                }
+
+                (variables, node)
+            })
+            .collect(),
+        universe: herbrand_universe(statements, depth).collect(),
+        statement: 0,
+        assignment: None,
+    }
+}
+
+impl Iterator for HerbrandUniverse {
+    type Item = Term;
+
+    fn next(&mut self) -> Option<Term> {
+        while self.next == self.terms.len() {
+            if self.depth == self.max_depth {
+                return None;
+            }
+
+            // Terms of the next depth are those with some term of the current depth
+            // as an argument
+            let mut terms = vec![];
+
+            for (function_symbol, degree) in &self.function_symbols {
+                let mut arguments = vec![0; *degree];
+
+                loop {
+                    if arguments.iter().any(|x| *x >= self.depth_start) {
+                        terms.push(Term::Function(
+                            function_symbol.clone(),
+                            arguments.iter().map(|x| self.terms[*x].clone()).collect(),
+                        ));
+                    }
+
+                    if !advance(&mut arguments, self.terms.len()) {
+                        break;
+                    }
+                }
+            }
+
+            if terms.is_empty() {
+                return None;
+            }
+
+            self.depth += 1;
+            self.depth_start = self.terms.len();
+            self.terms.extend(terms);
+        }
+
+        self.next += 1;
+
+        Some(self.terms[self.next - 1].clone())
+    }
+}
+
+impl Iterator for GroundInstances {
+    type Item = Formula;
+
+    fn next(&mut self) -> Option<Formula> {
+        while self.statement < self.statements.len() {
+            let (ref variables, ref matrix) = self.statements[self.statement];
+
+            let assignment = match self.assignment.take() {
+                None => Some(vec![0; variables.len()]),
+                Some(mut assignment) => {
+                    if advance(&mut assignment, self.universe.len()) {
+                        Some(assignment)
+                    } else {
+                        None
+                    }
+                }
+            };
+
+            match assignment {
+                Some(assignment) => {
+                    let node = variables
+                        .iter()
+                        .zip(&assignment)
+                        .fold(matrix.clone(), |node, (variable, x)| {
+                            replace_variable(node, variable, &self.universe[*x])
+                        });
+
+                    self.assignment = Some(assignment);
+
+                    return Some(into_formula(node));
+                }
+                None => self.statement += 1,
+            }
+        }
+
+        None
+    }
+}
+
+// Moves on to the next combination of indices, each less than 'len', in order, with
+// the last index changing the fastest. Returns false if there is none.
+fn advance(indices: &mut [usize], len: usize) -> bool {
+    for index in indices.iter_mut().rev() {
+        *index += 1;
+
+        if *index < len {
+            return true;
+        }
+
+        *index = 0;
+    }
+
+    false
+}
+
+// The singular terms and function symbols (with their degrees) of some statements,
+// in order of appearance
+#[derive(Default)]
+struct Signature {
+    singular_terms: Vec<SingularTerm>,
+    function_symbols: Vec<(FunctionSymbol, usize)>,
+}
+
+impl Visit for Signature {
+    fn visit_term(&mut self, term: &Term) {
+        if let Term::Function(function_symbol, terms) = term {
+            let function_symbol = (function_symbol.clone(), terms.len());
+
+            if !self.function_symbols.contains(&function_symbol) {
+                self.function_symbols.push(function_symbol);
+            }
+        }
+
+        visit_term(self, term)
+    }
+
+    fn visit_singular_term(&mut self, singular_term: &SingularTerm) {
+        if !self.singular_terms.contains(singular_term) {
+            self.singular_terms.push(singular_term.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_statements;
+
+    #[test]
+    fn herbrand_universe_by_depth() {
+        let statements = parse_statements("{∀x∃yL²xy, ∀x∀y∃zR³xyz}");
+
+        let terms = herbrand_universe(&statements, 1)
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(terms, vec!["a", "f(a)", "g(aa)"]);
+
+        let terms = herbrand_universe(&statements, 2)
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            terms,
+            vec![
+                "a",
+                "f(a)",
+                "g(aa)",
+                "f(f(a))",
+                "f(g(aa))",
+                "g(af(a))",
+                "g(ag(aa))",
+                "g(f(a)a)",
+                "g(f(a)f(a))",
+                "g(f(a)g(aa))",
+                "g(g(aa)a)",
+                "g(g(aa)f(a))",
+                "g(g(aa)g(aa))",
+            ]
+        );
+
+        // Without function symbols, the universe is just the singular terms
+        let statements = parse_statements("{F¹b, ∃x~F¹x, ∀xG¹x}");
+
+        let terms = herbrand_universe(&statements, 3)
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(terms, vec!["b", "a"]);
+    }
+
+    #[test]
+    fn ground_instances_of_statements() {
+        let statements = parse_statements("{∀x(F¹x ⊃ G¹x), F¹a, ∃y~G¹y}");
+
+        let instances = ground_instances(&statements, 0)
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            instances,
+            vec!["(~F¹a ∨ G¹a)", "(~F¹b ∨ G¹b)", "F¹a", "~G¹b"]
+        );
+    }
+}
@@ -7,14 +7,41 @@
 //! An Introduction to Logic Through Language_, by Ernest Lepore and Sam Cumming, trying
 //! to follow as closely as possible its grammar and rules.
 //!
-//! It uses separate syntax for statement sets, arguments, and sole statements, and so
-//! can automatically generate and analyse truth trees accordingly.
+//! It uses separate syntax for statement sets, arguments, equivalences, and sole statements,
+//! and so can automatically generate and analyse truth trees accordingly.
 //!
 //! The website for this library, which can be found [here](https://ixjf.github.io/logic-rs/),
 //! provides a full-featured demo of it.
 //!
-//! **Note: logic_rs currently doesn't support identity statements. It is planned
-//! for the future, however.**
+//! Identity statements (`a = b`, `~a = b`, and `x = y` inside formulas) are supported,
+//! along with the identity rules of the book (substitution of identicals, and closure
+//! of any branch containing `~a = a`).
+//!
+//! The biconditional connective (`(A ≡ B)`) is also supported, with its own
+//! truth tree rules, rather than as an abbreviation of two conditionals.
+//!
+//! Every symbol also has an ASCII alternative, which produces the exact same parse tree:
+//! `->` for `⊃`, `<->` for `≡`, `v` or `|` for `∨`, `Ax` or `forall x` for `∀x`,
+//! `Ex` or `exists x` for `∃x`, `therefore` or `:.` for `∴`, `<=>` for `⟚`, `P^2` for `P²`,
+//! and `a_1` for `a₁`.
+//! Since `v` is also a singular term, it must be separated from any terms before it
+//! by whitespace (`(F^1a v G^1a)`, not `(F^1av G^1a)`).
+//!
+//! Statements, formulas and terms implement `Display`, which writes them back in the
+//! notation of the book. [DisplayOptions](struct.DisplayOptions.html) can be used to display them in the ASCII
+//! notation instead, or fully parenthesized. Either way, parsing the result yields the same statement.
+//!
+//! When a truth tree has a completed open branch, [TruthTree::interpretation](struct.TruthTree.html#method.interpretation) reads off
+//! an interpretation from it (a domain, the denotation of each singular term, the extension of
+//! each predicate letter, and the truth value of each simple statement letter), which is checked
+//! to make every initial statement true. For an invalid argument, that is a counterexample.
+//! Interpretations can also be made up with [Interpretation::new](struct.Interpretation.html#method.new),
+//! and any statement evaluated on them with [Interpretation::evaluate](struct.Interpretation.html#method.evaluate).
+//!
+//! Statement sets, arguments and single statements whose statements are built only from simple
+//! statement letters and connectives also have a [TruthTable](struct.TruthTable.html)
+//! (see e.g. [Argument::truth_table](struct.Argument.html#method.truth_table)), with a column for
+//! each sub-statement.
 //!
 //! # Usage
 //! Validating some formula is as simple as:
@@ -35,7 +62,7 @@
 //! is just as simple:
 //!
 //! ```
-//! # use logic_rs::{parse_input, InputKind};
+//! # use logic_rs::{parse_input, InputKind, Verdict};
 //! match parse_input("(∀x)(B¹x ⊃ (L²xm ⊃ L²bx))") {
 //!     Ok(input_kind) => match input_kind {
 //!         InputKind::Statement(st) => {
@@ -45,7 +72,7 @@
 //!                 truth_tree_negation_of_stmt
 //!                 ) = st.is_contingency();
 //!             
-//!             assert_eq!(is_contingency, true);
+//!             assert_eq!(is_contingency, Verdict::Yes);
 //!         },
 //!         _ => assert!(false)
 //!     },
@@ -58,9 +85,20 @@
 //!  - Handle potential [ParseError](struct.ParseError.html)s
 //!  - Match on the `Ok` value
 //!  - Call one or more of the is_* methods of the [StatementSet](struct.StatementSet.html), [Argument](struct.Argument.html),
-//! or [SingleStatement](struct.SingleStatement.html) to run the truth tree algorithm
+//! [Equivalence](struct.Equivalence.html) or [SingleStatement](struct.SingleStatement.html) to run the truth tree algorithm
 //!  - Do something with the truth tree(s) or the analysis of it
 //!
+//! Statement sets, arguments, equivalences and single statements can also be created from statements
+//! built in code, with [StatementSet::new](struct.StatementSet.html#method.new),
+//! [Argument::new](struct.Argument.html#method.new),
+//! [Equivalence::new](struct.Equivalence.html#method.new) and
+//! [SingleStatement::new](struct.SingleStatement.html#method.new). The [builder](builder/index.html)
+//! module makes it easier to build those statements, and the [visit](visit/index.html) module to
+//! traverse and rewrite them. The [normal_form](normal_form/index.html) module converts statements
+//! into negation, conjunctive, disjunctive and prenex normal form, and Skolemizes them, and the
+//! [herbrand](herbrand/index.html) module enumerates the Herbrand universe and ground instances of
+//! a set of statements.
+//!
 //! # Language and Truth Tree Algorithm
 //! A specification of the language can be found [here](https://github.com/ixjf/logic-rs/wiki/Language).
 //!
@@ -87,6 +125,27 @@
 //! One thing can be guaranteed, though, and that is that, _unless there is a bug_, the algorithm
 //! will **always** correctly classify an unsatisfiable set of statements, which also implies that
 //! if it does get stuck in an infinite loop, then the initial set of statements is satisfiable.
+//!
+//! To avoid getting stuck, [Limits](struct.Limits.html) can be set on the number of statements in the tree,
+//! the length of its branches, the number of applications of the universal quantifier rule, and the time the
+//! algorithm may run for (see e.g. [StatementSet::with_limits](struct.StatementSet.html#method.with_limits)).
+//! If any limit is reached, the answer is [Verdict::Unknown](enum.Verdict.html#variant.Unknown), along with the
+//! partial truth tree.
+//!
+//! The algorithm can also be stopped from another thread through a [CancellationToken](struct.CancellationToken.html)
+//! (see e.g. [StatementSet::with_cancellation](struct.StatementSet.html#method.with_cancellation)), with the same result.
+//!
+//! To follow the construction of a truth tree one rule application at a time (e.g. to animate it),
+//! [TruthTreeMethod::steps](struct.TruthTreeMethod.html#method.steps) returns an iterator over the changes
+//! made to the tree by the algorithm.
+//!
+//! A truth tree can be drawn in plain text with [TruthTree::to_text](struct.TruthTree.html#method.to_text),
+//! drawn as an SVG image with [TruthTree::to_svg](struct.TruthTree.html#method.to_svg) (both place statements
+//! as computed by [TruthTree::layout](struct.TruthTree.html#method.layout)),
+//! exported to the DOT language of Graphviz with [TruthTree::to_dot](struct.TruthTree.html#method.to_dot),
+//! or exported to LaTeX (for the `prooftrees` package) with [TruthTree::to_latex](struct.TruthTree.html#method.to_latex).
+//! [TruthTree::listing](struct.TruthTree.html#method.listing) lists the statements on numbered lines with their
+//! justifications, as the book does.
 
 extern crate pest;
 #[macro_use]
@@ -100,47 +159,157 @@ extern crate serde;
 
 extern crate snowflake;
 
+pub mod builder;
+pub mod herbrand;
+pub mod normal_form;
 mod parser;
 mod validity;
+pub mod visit;
 
-use parser::Input;
+use parser::{validate_statement, Input, ParseTree};
 use parser::Parser;
-use validity::TruthTreeMethod;
 
-pub use parser::ParseError;
+pub use parser::{ExpectedToken, ParseError, ParseErrorKind, ValidationError};
 pub use parser::{
-    Degree, Formula, PredicateLetter, SimpleStatementLetter, SingularTerm, Statement, Subscript,
-    Term, Variable,
+    Degree, DisplayOptions, Formula, FunctionSymbol, Notation, Parenthesization, PredicateLetter,
+    SimpleStatementLetter, SingularTerm, SourceSpan, SpanTree, Statement, Subscript, Term,
+    Variable,
 };
 pub use validity::{
-    Branch, BranchDirectDescendantsIdsIter, BranchDirectDescendantsIter, BranchNode,
-    BranchNodeLocation, DerivationId, DownwardsBranchesIdsIter, DownwardsBranchesIter, Rule,
-    StatementIdsIter, StatementsIter, TreeId, TruthTree, UpwardsBranchesIdsIter,
-    UpwardsBranchesIter,
+    Branch, BranchDirectDescendantsIdsIter, BranchDirectDescendantsIter, BranchLayout, BranchNode,
+    BranchNodeLocation, CancellationToken, DerivationId, DownwardsBranchesIdsIter,
+    DownwardsBranchesIter, EvaluationError, Interpretation, Interruption, Justification, Layout,
+    Limits, Listing, ListingEntry, ListingLine, Object, Rule, StatementIdsIter, StatementLayout,
+    StatementsIter, Step, Steps, TreeId, TruthTable, TruthTableRow, TruthTree, TruthTreeMethod,
+    UpwardsBranchesIdsIter, UpwardsBranchesIter,
 };
 
-/// A statement set parsed from the input, through which one can check its
-/// consistency.
+/// The answer to whether some input has some property (e.g. whether an argument
+/// is formally valid).
+///
+/// The answer is only `Unknown` if the truth tree algorithm was stopped by some limit
+/// (see [Limits](struct.Limits.html)) before it could find it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Yes,
+    No,
+    Unknown(Interruption),
+}
+
+impl Verdict {
+    // A property that holds if all branches of the truth tree close
+    fn from_closed_tree(truth_tree: &TruthTree) -> Self {
+        if !truth_tree.is_open() {
+            return Verdict::Yes;
+        }
+
+        match truth_tree.interruption() {
+            // Even if the algorithm was interrupted, an open branch may already be
+            // enough to tell that the initial statements can all be true
+            Some(interruption) if truth_tree.interpretation().is_none() => {
+                Verdict::Unknown(interruption.clone())
+            }
+            _ => Verdict::No,
+        }
+    }
+
+    fn negate(self) -> Self {
+        match self {
+            Verdict::Yes => Verdict::No,
+            Verdict::No => Verdict::Yes,
+            unknown => unknown,
+        }
+    }
+}
+
+/// A statement set, through which one can check its consistency.
 pub struct StatementSet {
     statements: Vec<Statement>,
+    spans: Option<Vec<SpanTree>>, // Only if parsed from the input
+    limits: Limits,
+    cancellation: CancellationToken,
 }
 
 impl StatementSet {
-    /// Returns as the first field of the tuple a boolean representing whether
-    /// the statement set is consistent, and the proof truth tree as the second.
+    /// Creates a statement set from statements that weren't parsed from some input.
+    ///
+    /// Fails if some statement is semantically invalid, i.e. if
+    /// [parse_input](fn.parse_input.html) would fail with some error of one of the kinds
+    /// [DegreeMismatch](enum.ParseErrorKind.html#variant.DegreeMismatch),
+    /// [VariableOutOfScope](enum.ParseErrorKind.html#variant.VariableOutOfScope) or
+    /// [VariableAlreadyBound](enum.ParseErrorKind.html#variant.VariableAlreadyBound)
+    /// for it ([ValidationError::Parse](enum.ValidationError.html#variant.Parse)), or if it
+    /// contains something that can't be written as input at all: a function term
+    /// ([ValidationError::FunctionTermNotAllowed](enum.ValidationError.html#variant.FunctionTermNotAllowed)),
+    /// a predicate letter of degree 0
+    /// ([ValidationError::ZeroDegree](enum.ValidationError.html#variant.ZeroDegree)),
+    /// a subscript 0
+    /// ([ValidationError::ZeroSubscript](enum.ValidationError.html#variant.ZeroSubscript)),
+    /// or a predicate or identity formula without variables, which the parser reads as a
+    /// statement instead
+    /// ([ValidationError::FormulaWithoutVariable](enum.ValidationError.html#variant.FormulaWithoutVariable)).
+    /// The first error found is returned.
+    ///
+    /// Also fails if `statements` is empty
+    /// ([ValidationError::EmptyStatementSet](enum.ValidationError.html#variant.EmptyStatementSet)).
+    pub fn new(statements: Vec<Statement>) -> Result<Self, ValidationError> {
+        if statements.is_empty() {
+            return Err(ValidationError::EmptyStatementSet);
+        }
+
+        for statement in &statements {
+            validate_statement(statement)?;
+        }
+
+        Ok(StatementSet {
+            statements,
+            spans: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
+        })
+    }
+
+    /// Returns the statements of the set.
+    pub fn statements(&self) -> &[Statement] {
+        &self.statements
+    }
+
+    /// Returns where each statement of the set is in the input, in the order they're in,
+    /// or `None` if the statement set wasn't parsed from some input.
+    /// See [SpanTree](struct.SpanTree.html).
+    pub fn spans(&self) -> Option<&[SpanTree]> {
+        self.spans.as_ref().map(|x| &x[..])
+    }
+
+    /// Sets the limits on how much work the truth tree algorithm may do.
+    /// See [Limits](struct.Limits.html).
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Allows the truth tree algorithm to be cancelled through `token`, e.g. from another thread.
+    /// See [CancellationToken](struct.CancellationToken.html).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Returns as the first field of the tuple whether the statement set is consistent,
+    /// and the proof truth tree as the second.
     ///
     /// # Examples
     ///
     /// ```
     /// # use std::error::Error;
-    /// # use logic_rs::{parse_input, InputKind};
+    /// # use logic_rs::{parse_input, InputKind, Verdict};
     /// # fn main() -> Result<(), Box<Error>> {
     /// let parsed_input = parse_input("{(A & B)}")?;
     ///
     /// match parsed_input {
     ///     InputKind::StatementSet(st_set) => {
     ///         let (is_consistent, truth_tree) = st_set.is_consistent();
-    ///         assert_eq!(is_consistent, true);
+    ///         assert_eq!(is_consistent, Verdict::Yes);
     ///     },
     ///     _ => assert!(false)
     /// }
@@ -149,38 +318,110 @@ impl StatementSet {
     /// # }
     /// ```
     ///
-    pub fn is_consistent(&self) -> (bool, TruthTree) {
-        let truth_tree = TruthTreeMethod::new(&self.statements).compute();
+    pub fn is_consistent(&self) -> (Verdict, TruthTree) {
+        let truth_tree = TruthTreeMethod::new(&self.statements)
+            .with_limits(self.limits.clone())
+            .with_cancellation(self.cancellation.clone())
+            .compute();
 
         // A statement set is consistent if at least one branch stays open,
         // i.e. it's possible for all the statements to be true at the same
         // time
-        (truth_tree.is_open(), truth_tree)
+        (Verdict::from_closed_tree(&truth_tree).negate(), truth_tree)
+    }
+
+    /// Returns the truth table of the statement set, with the rows on which every
+    /// statement is true highlighted (so the statement set is consistent if and only if
+    /// some row is highlighted), or `None` if some statement isn't built only from simple
+    /// statement letters and connectives, or if there are more than
+    /// [TruthTable::MAX_LETTERS](struct.TruthTable.html#associatedconstant.MAX_LETTERS) letters.
+    ///
+    /// See [TruthTable](struct.TruthTable.html).
+    pub fn truth_table(&self) -> Option<TruthTable> {
+        TruthTable::new(&self.statements, |x| x.iter().all(|x| *x))
     }
 }
 
-/// An argument parsed from the input, through which one can check its formal validity.
+/// An argument, through which one can check its formal validity.
 pub struct Argument {
     premises: Vec<Statement>,
     conclusion: Statement,
+    spans: Option<Vec<SpanTree>>, // Premises, then conclusion, only if parsed from the input
+    limits: Limits,
+    cancellation: CancellationToken,
 }
 
 impl Argument {
-    /// Returns as the first field of the tuple a boolean representing whether
-    /// the argument is formally valid, and the proof truth tree as the second.
+    /// Creates an argument from statements that weren't parsed from some input.
+    ///
+    /// Fails if some statement is semantically invalid, the same way
+    /// [StatementSet::new](struct.StatementSet.html#method.new) does.
+    pub fn new(premises: Vec<Statement>, conclusion: Statement) -> Result<Self, ValidationError> {
+        for statement in premises.iter().chain(Some(&conclusion)) {
+            validate_statement(statement)?;
+        }
+
+        Ok(Argument {
+            premises,
+            conclusion,
+            spans: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
+        })
+    }
+
+    /// Returns the premises of the argument.
+    pub fn premises(&self) -> &[Statement] {
+        &self.premises
+    }
+
+    /// Returns the conclusion of the argument.
+    pub fn conclusion(&self) -> &Statement {
+        &self.conclusion
+    }
+
+    /// Returns where each premise is in the input, in the order they're in,
+    /// or `None` if the argument wasn't parsed from some input.
+    /// See [SpanTree](struct.SpanTree.html).
+    pub fn premise_spans(&self) -> Option<&[SpanTree]> {
+        self.spans.as_ref().map(|x| &x[..self.premises.len()])
+    }
+
+    /// Returns where the conclusion is in the input, or `None` if the argument
+    /// wasn't parsed from some input. See [SpanTree](struct.SpanTree.html).
+    pub fn conclusion_span(&self) -> Option<&SpanTree> {
+        self.spans.as_ref().map(|x| &x[self.premises.len()])
+    }
+
+    /// Sets the limits on how much work the truth tree algorithm may do.
+    /// See [Limits](struct.Limits.html).
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Allows the truth tree algorithm to be cancelled through `token`, e.g. from another thread.
+    /// See [CancellationToken](struct.CancellationToken.html).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Returns as the first field of the tuple whether the argument is formally valid,
+    /// and the proof truth tree as the second.
     ///
     /// # Examples
     ///
     /// ```
     /// # use std::error::Error;
-    /// # use logic_rs::{parse_input, InputKind};
+    /// # use logic_rs::{parse_input, InputKind, Verdict};
     /// # fn main() -> Result<(), Box<Error>> {
     /// let parsed_input = parse_input("A ∴ B")?;
     ///
     /// match parsed_input {
     ///     InputKind::Argument(arg) => {
     ///         let (is_valid, truth_tree) = arg.is_valid();
-    ///         assert_eq!(is_valid, false);
+    ///         assert_eq!(is_valid, Verdict::No);
     ///     },
     ///     _ => assert!(false)
     /// }
@@ -189,18 +430,173 @@ impl Argument {
     /// # }
     /// ```
     ///
-    pub fn is_valid(&self) -> (bool, TruthTree) {
+    pub fn is_valid(&self) -> (Verdict, TruthTree) {
         // Transform into statement list of form '<premise>, <premise>,...,negation of <conclusion>'
         let mut statements = self.premises.clone();
         statements.push(Statement::LogicalNegation(Box::new(
             self.conclusion.clone(),
         )));
 
-        let truth_tree = TruthTreeMethod::new(&statements).compute();
+        let truth_tree = TruthTreeMethod::new(&statements)
+            .with_limits(self.limits.clone())
+            .with_cancellation(self.cancellation.clone())
+            .compute();
 
         // An argument is valid if all branches close, i.e. it's not possible for
         // the premises to be true while the conclusion is false
-        (!truth_tree.is_open(), truth_tree)
+        (Verdict::from_closed_tree(&truth_tree), truth_tree)
+    }
+
+    /// Returns the truth table of the argument, with the counterexamples (i.e. the rows on
+    /// which every premise is true and the conclusion false) highlighted, so the argument is
+    /// valid if and only if no row is highlighted. Returns `None` if some statement isn't built
+    /// only from simple statement letters and connectives, or if there are more than
+    /// [TruthTable::MAX_LETTERS](struct.TruthTable.html#associatedconstant.MAX_LETTERS) letters.
+    ///
+    /// See [TruthTable](struct.TruthTable.html).
+    pub fn truth_table(&self) -> Option<TruthTable> {
+        let mut statements = self.premises.clone();
+        statements.push(self.conclusion.clone());
+
+        TruthTable::new(&statements, |x| {
+            let (conclusion, premises) = x.split_last().unwrap();
+
+            premises.iter().all(|x| *x) && !conclusion
+        })
+    }
+}
+
+/// Two statements, through which one can check whether they are logically equivalent.
+pub struct Equivalence {
+    lhs: Statement,
+    rhs: Statement,
+    spans: Option<Vec<SpanTree>>, // Left-hand side, then right-hand side, only if parsed from the input
+    limits: Limits,
+    cancellation: CancellationToken,
+}
+
+impl Equivalence {
+    /// Creates an equivalence from statements that weren't parsed from some input.
+    ///
+    /// Fails if some statement is semantically invalid, the same way
+    /// [StatementSet::new](struct.StatementSet.html#method.new) does.
+    pub fn new(lhs: Statement, rhs: Statement) -> Result<Self, ValidationError> {
+        validate_statement(&lhs)?;
+        validate_statement(&rhs)?;
+
+        Ok(Equivalence {
+            lhs,
+            rhs,
+            spans: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
+        })
+    }
+
+    /// Returns the statement on the left-hand side.
+    pub fn lhs(&self) -> &Statement {
+        &self.lhs
+    }
+
+    /// Returns the statement on the right-hand side.
+    pub fn rhs(&self) -> &Statement {
+        &self.rhs
+    }
+
+    /// Returns where the statement on the left-hand side is in the input, or `None`
+    /// if the equivalence wasn't parsed from some input. See [SpanTree](struct.SpanTree.html).
+    pub fn lhs_span(&self) -> Option<&SpanTree> {
+        self.spans.as_ref().map(|x| &x[0])
+    }
+
+    /// Returns where the statement on the right-hand side is in the input, or `None`
+    /// if the equivalence wasn't parsed from some input. See [SpanTree](struct.SpanTree.html).
+    pub fn rhs_span(&self) -> Option<&SpanTree> {
+        self.spans.as_ref().map(|x| &x[1])
+    }
+
+    /// Sets the limits on how much work the truth tree algorithm may do.
+    /// See [Limits](struct.Limits.html).
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Allows the truth tree algorithm to be cancelled through `token`, e.g. from another thread.
+    /// See [CancellationToken](struct.CancellationToken.html).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Returns as the first field of the tuple whether the two statements are logically
+    /// equivalent, and the proof truth trees as second and third.
+    /// The first truth tree proves that the argument from the left-hand side to the
+    /// right-hand side is valid, while the second truth tree proves the same of the
+    /// argument from the right-hand side to the left-hand side.
+    ///
+    /// If the statements are not equivalent, the fourth field is a countermodel: an
+    /// interpretation on which one of them is true and the other false, read off the
+    /// first of the truth trees that has a completed open branch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # use logic_rs::{parse_input, InputKind, Verdict};
+    /// # fn main() -> Result<(), Box<Error>> {
+    /// let parsed_input = parse_input("(A ⊃ B) ⟚ (B ⊃ A)")?;
+    ///
+    /// match parsed_input {
+    ///     InputKind::Equivalence(eq) => {
+    ///         let (
+    ///             is_equivalent,
+    ///             truth_tree_lhs_to_rhs,
+    ///             truth_tree_rhs_to_lhs,
+    ///             countermodel
+    ///             ) = eq.is_equivalent();
+    ///         assert_eq!(is_equivalent, Verdict::No);
+    ///         assert!(countermodel.is_some());
+    ///     },
+    ///     _ => assert!(false)
+    /// }
+    /// #
+    /// #    Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn is_equivalent(&self) -> (Verdict, TruthTree, TruthTree, Option<Interpretation>) {
+        // Two statements are equivalent if each can be validly inferred from
+        // the other, i.e. it's not possible for one to be true while the
+        // other is false
+        let argument = |premise: &Statement, conclusion: &Statement| Argument {
+            premises: vec![premise.clone()],
+            conclusion: conclusion.clone(),
+            spans: None,
+            limits: self.limits.clone(),
+            cancellation: self.cancellation.clone(),
+        };
+
+        let lhs_to_rhs = argument(&self.lhs, &self.rhs).is_valid();
+        let rhs_to_lhs = argument(&self.rhs, &self.lhs).is_valid();
+
+        let verdict = match (&lhs_to_rhs.0, &rhs_to_lhs.0) {
+            (Verdict::Yes, Verdict::Yes) => Verdict::Yes,
+            (Verdict::No, _) | (_, Verdict::No) => Verdict::No,
+            (Verdict::Unknown(interruption), _) | (_, Verdict::Unknown(interruption)) => {
+                Verdict::Unknown(interruption.clone())
+            }
+        };
+
+        let countermodel = match verdict {
+            Verdict::No => lhs_to_rhs
+                .1
+                .interpretation()
+                .or_else(|| rhs_to_lhs.1.interpretation()),
+            _ => None,
+        };
+
+        (verdict, lhs_to_rhs.1, rhs_to_lhs.1, countermodel)
     }
 }
 
@@ -209,24 +605,67 @@ impl Argument {
 /// contingency.
 pub struct SingleStatement {
     statement: Statement,
+    span: Option<SpanTree>, // Only if parsed from the input
+    limits: Limits,
+    cancellation: CancellationToken,
 }
 
 impl SingleStatement {
-    /// Returns as the first field of the tuple a boolean representing whether
-    /// the statement is a contradiction, and the proof truth tree as the second.
+    /// Creates a single statement from a statement that wasn't parsed from some input.
+    ///
+    /// Fails if the statement is semantically invalid, the same way
+    /// [StatementSet::new](struct.StatementSet.html#method.new) does.
+    pub fn new(statement: Statement) -> Result<Self, ValidationError> {
+        validate_statement(&statement)?;
+
+        Ok(SingleStatement {
+            statement,
+            span: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
+        })
+    }
+
+    /// Returns the statement.
+    pub fn statement(&self) -> &Statement {
+        &self.statement
+    }
+
+    /// Returns where the statement is in the input, or `None` if it wasn't parsed
+    /// from some input. See [SpanTree](struct.SpanTree.html).
+    pub fn span(&self) -> Option<&SpanTree> {
+        self.span.as_ref()
+    }
+
+    /// Sets the limits on how much work the truth tree algorithm may do.
+    /// See [Limits](struct.Limits.html).
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Allows the truth tree algorithm to be cancelled through `token`, e.g. from another thread.
+    /// See [CancellationToken](struct.CancellationToken.html).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = token;
+        self
+    }
+
+    /// Returns as the first field of the tuple whether the statement is a contradiction,
+    /// and the proof truth tree as the second.
     ///
     /// # Examples
     ///
     /// ```
     /// # use std::error::Error;
-    /// # use logic_rs::{parse_input, InputKind};
+    /// # use logic_rs::{parse_input, InputKind, Verdict};
     /// # fn main() -> Result<(), Box<Error>> {
     /// let parsed_input = parse_input("(A & ~A)")?;
     ///
     /// match parsed_input {
     ///     InputKind::Statement(st) => {
     ///         let (is_contradiction, truth_tree) = st.is_contradiction();
-    ///         assert_eq!(is_contradiction, true);
+    ///         assert_eq!(is_contradiction, Verdict::Yes);
     ///     },
     ///     _ => assert!(false)
     /// }
@@ -235,30 +674,33 @@ impl SingleStatement {
     /// # }
     /// ```
     ///
-    pub fn is_contradiction(&self) -> (bool, TruthTree) {
-        let truth_tree = TruthTreeMethod::new(&vec![self.statement.clone()]).compute();
+    pub fn is_contradiction(&self) -> (Verdict, TruthTree) {
+        let truth_tree = TruthTreeMethod::new(&vec![self.statement.clone()])
+            .with_limits(self.limits.clone())
+            .with_cancellation(self.cancellation.clone())
+            .compute();
 
         // A statement is a contradiction if all branches for a truth tree
         // with that single initial statement close
         // i.e. it's not possible for the statement to be true
-        (!truth_tree.is_open(), truth_tree)
+        (Verdict::from_closed_tree(&truth_tree), truth_tree)
     }
 
-    /// Returns as the first field of the tuple a boolean representing whether
-    /// the statement is a tautology, and the proof truth tree as the second.
+    /// Returns as the first field of the tuple whether the statement is a tautology,
+    /// and the proof truth tree as the second.
     ///
     /// # Examples
     ///
     /// ```
     /// # use std::error::Error;
-    /// # use logic_rs::{parse_input, InputKind};
+    /// # use logic_rs::{parse_input, InputKind, Verdict};
     /// # fn main() -> Result<(), Box<Error>> {
     /// let parsed_input = parse_input("(A ∨ ~A)")?;
     ///
     /// match parsed_input {
     ///     InputKind::Statement(st) => {
     ///         let (is_tautology, truth_tree) = st.is_tautology();
-    ///         assert_eq!(is_tautology, true);
+    ///         assert_eq!(is_tautology, Verdict::Yes);
     ///     },
     ///     _ => assert!(false)
     /// }
@@ -267,18 +709,21 @@ impl SingleStatement {
     /// # }
     /// ```
     ///
-    pub fn is_tautology(&self) -> (bool, TruthTree) {
+    pub fn is_tautology(&self) -> (Verdict, TruthTree) {
         // A statement is a tautology if its negation is a contradiction
         // So we negate self.statement and do the same thing as in
         // is_contradiction
         let statement = Statement::LogicalNegation(Box::new(self.statement.clone()));
-        let truth_tree = TruthTreeMethod::new(&vec![statement]).compute();
+        let truth_tree = TruthTreeMethod::new(&vec![statement])
+            .with_limits(self.limits.clone())
+            .with_cancellation(self.cancellation.clone())
+            .compute();
 
-        (!truth_tree.is_open(), truth_tree)
+        (Verdict::from_closed_tree(&truth_tree), truth_tree)
     }
 
-    /// Returns as the first field of the tuple a boolean representing whether
-    /// the statement is a contingency, and the proof truth trees as second and third.
+    /// Returns as the first field of the tuple whether the statement is a contingency,
+    /// and the proof truth trees as second and third.
     /// The first truth tree proves that the statement is not a contradiction,
     /// while the second truth tree proves that the statement is also not
     /// a tautology.
@@ -287,7 +732,7 @@ impl SingleStatement {
     ///
     /// ```
     /// # use std::error::Error;
-    /// # use logic_rs::{parse_input, InputKind};
+    /// # use logic_rs::{parse_input, InputKind, Verdict};
     /// # fn main() -> Result<(), Box<Error>> {
     /// let parsed_input = parse_input("A")?;
     ///
@@ -298,7 +743,7 @@ impl SingleStatement {
     ///             truth_tree_contradiction,
     ///             truth_tree_tautology
     ///             ) = st.is_contingency();
-    ///         assert_eq!(is_contingency, true);
+    ///         assert_eq!(is_contingency, Verdict::Yes);
     ///     },
     ///     _ => assert!(false)
     /// }
@@ -307,17 +752,33 @@ impl SingleStatement {
     /// # }
     /// ```
     ///
-    pub fn is_contingency(&self) -> (bool, TruthTree, TruthTree) {
+    pub fn is_contingency(&self) -> (Verdict, TruthTree, TruthTree) {
         // A statement is a contingency if it's neither a contradiction
         // nor a tautology
         let is_contradiction = self.is_contradiction();
         let is_tautology = self.is_tautology();
 
-        (
-            !is_contradiction.0 && !is_tautology.0,
-            is_contradiction.1,
-            is_tautology.1,
-        )
+        let verdict = match (&is_contradiction.0, &is_tautology.0) {
+            (Verdict::No, Verdict::No) => Verdict::Yes,
+            (Verdict::Yes, _) | (_, Verdict::Yes) => Verdict::No,
+            (Verdict::Unknown(interruption), _) | (_, Verdict::Unknown(interruption)) => {
+                Verdict::Unknown(interruption.clone())
+            }
+        };
+
+        (verdict, is_contradiction.1, is_tautology.1)
+    }
+
+    /// Returns the truth table of the statement, with the rows on which it is false
+    /// highlighted, or `None` if it isn't built only from simple statement letters and
+    /// connectives, or if it has more than
+    /// [TruthTable::MAX_LETTERS](struct.TruthTable.html#associatedconstant.MAX_LETTERS) letters.
+    /// The statement is a tautology if no row is highlighted, and a
+    /// contradiction if every row is.
+    ///
+    /// See [TruthTable](struct.TruthTable.html).
+    pub fn truth_table(&self) -> Option<TruthTable> {
+        TruthTable::new(&[self.statement.clone()], |x| !x[0])
     }
 }
 
@@ -325,10 +786,11 @@ impl SingleStatement {
 pub enum InputKind {
     StatementSet(StatementSet),
     Argument(Argument),
+    Equivalence(Equivalence),
     Statement(SingleStatement),
 }
 
-/// Parses some statement set, argument, or statement. Takes in input
+/// Parses some statement set, argument, equivalence, or statement. Takes in input
 /// as a string following the specification from
 /// [here](https://github.com/ixjf/logic-rs/wiki/Language).
 ///
@@ -337,24 +799,68 @@ pub fn parse_input(input: &str) -> Result<InputKind, ParseError> {
     let parser = Parser::new();
 
     match parser.parse(input) {
-        Ok(parse_tree) => match parse_tree.0 {
-            Input::StatementSet(statements) => {
-                Ok(InputKind::StatementSet(StatementSet { statements }))
-            }
-            Input::Argument(premises, conclusion) => Ok(InputKind::Argument(Argument {
+        Ok(parse_tree) => Ok(InputKind::from_parse_tree(parse_tree)),
+        Err(e) => Err(e),
+    }
+}
+
+/// Same as [parse_input](fn.parse_input.html), except that if the input is invalid,
+/// all errors found are returned at once rather than only the first one, in order of
+/// where they occur in the input.
+///
+/// Each statement of a statement set, argument or equivalence is checked on its own, so errors in
+/// different statements are all reported, even if some statement isn't syntactically valid.
+/// The vector of errors is never empty.
+pub fn parse_input_with_diagnostics(input: &str) -> Result<InputKind, Vec<ParseError>> {
+    let parser = Parser::new();
+
+    match parser.parse_with_diagnostics(input) {
+        Ok(parse_tree) => Ok(InputKind::from_parse_tree(parse_tree)),
+        Err(e) => Err(e),
+    }
+}
+
+impl InputKind {
+    fn from_parse_tree(parse_tree: ParseTree) -> Self {
+        let mut spans = parse_tree.1;
+
+        match parse_tree.0 {
+            Input::StatementSet(statements) => InputKind::StatementSet(StatementSet {
+                statements,
+                spans: Some(spans),
+                limits: Limits::default(),
+                cancellation: CancellationToken::new(),
+            }),
+            Input::Argument(premises, conclusion) => InputKind::Argument(Argument {
                 premises,
                 conclusion,
-            })),
-            Input::Statement(statement) => Ok(InputKind::Statement(SingleStatement { statement })),
-        },
-        Err(e) => Err(e),
+                spans: Some(spans),
+                limits: Limits::default(),
+                cancellation: CancellationToken::new(),
+            }),
+            Input::Equivalence(lhs, rhs) => InputKind::Equivalence(Equivalence {
+                lhs,
+                rhs,
+                spans: Some(spans),
+                limits: Limits::default(),
+                cancellation: CancellationToken::new(),
+            }),
+            Input::Statement(statement) => InputKind::Statement(SingleStatement {
+                statement,
+                span: spans.pop(),
+                limits: Limits::default(),
+                cancellation: CancellationToken::new(),
+            }),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use parser::{SimpleStatementLetter, Subscript};
+    use parser::{
+        Degree, ParseErrorKind, PredicateLetter, SimpleStatementLetter, SingularTerm, Subscript,
+    };
 
     #[test]
     fn parse_input() {
@@ -376,6 +882,111 @@ mod tests {
         }
     }
 
+    #[test]
+    fn constructors_validate_statements() {
+        let a = Statement::Simple(SimpleStatementLetter('A', Subscript(None)));
+        let invalid = Statement::Singular(
+            PredicateLetter('F', Subscript(None), Degree(2)),
+            vec![SingularTerm('a', Subscript(None))],
+        );
+
+        let statement_set = StatementSet::new(vec![a.clone()]).unwrap();
+        assert_eq!(statement_set.statements(), &[a.clone()]);
+        assert_eq!(statement_set.spans(), None);
+        assert_eq!(statement_set.is_consistent().0, Verdict::Yes);
+
+        match StatementSet::new(vec![]) {
+            Err(ValidationError::EmptyStatementSet) => {}
+            _ => assert!(false),
+        }
+
+        match StatementSet::new(vec![a.clone(), invalid.clone()]) {
+            Err(ValidationError::Parse(ParseErrorKind::DegreeMismatch { terms: 1, .. })) => {}
+            _ => assert!(false),
+        }
+
+        let argument = Argument::new(vec![a.clone()], a.clone()).unwrap();
+        assert_eq!(argument.premises(), &[a.clone()]);
+        assert_eq!(argument.conclusion(), &a);
+        assert_eq!(argument.is_valid().0, Verdict::Yes);
+
+        assert!(Argument::new(vec![a.clone()], invalid.clone()).is_err());
+
+        let single_statement = SingleStatement::new(a.clone()).unwrap();
+        assert_eq!(single_statement.statement(), &a);
+        assert_eq!(single_statement.span(), None);
+
+        assert!(SingleStatement::new(invalid).is_err());
+    }
+
+    #[test]
+    fn parsed_input_has_spans() {
+        match super::parse_input("A, (B & C) ∴ D") {
+            Ok(InputKind::Argument(argument)) => {
+                let premise_spans = argument.premise_spans().unwrap();
+
+                assert_eq!(premise_spans.len(), 2);
+                assert_eq!((premise_spans[0].span.start, premise_spans[0].span.end), (0, 1));
+                assert_eq!((premise_spans[1].span.start, premise_spans[1].span.end), (3, 10));
+                assert_eq!(argument.conclusion_span().unwrap().span.start, 15);
+            }
+            _ => assert!(false),
+        }
+
+        match super::parse_input("~A") {
+            Ok(InputKind::Statement(single_statement)) => {
+                let span_tree = single_statement.span().unwrap();
+
+                assert_eq!(span_tree.span.end, 2);
+                assert_eq!(span_tree.children.len(), 1);
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn parse_input_with_diagnostics() {
+        match super::parse_input_with_diagnostics("{A &, B¹ab, (C}") {
+            Ok(_) => assert!(false),
+            Err(errors) => {
+                assert_eq!(errors.len(), 3);
+
+                match errors[0].kind {
+                    ParseErrorKind::UnexpectedToken { .. } => {}
+                    _ => assert!(false),
+                }
+
+                match errors[1].kind {
+                    ParseErrorKind::DegreeMismatch { terms: 2, .. } => {}
+                    _ => assert!(false),
+                }
+
+                match errors[2].kind {
+                    ParseErrorKind::UnexpectedToken { .. } => {}
+                    _ => assert!(false),
+                }
+
+                assert!(errors[0].span.0 < errors[1].span.0);
+                assert!(errors[1].span.0 < errors[2].span.0);
+            }
+        }
+
+        match super::parse_input_with_diagnostics("A¹x, B ∴ C²a") {
+            Ok(_) => assert!(false),
+            Err(errors) => {
+                assert_eq!(errors.len(), 2);
+                assert_eq!(errors[0].location, (1, 3));
+                assert_eq!(errors[1].location, (1, 10));
+            }
+        }
+
+        // Errors that aren't in any statement are reported too
+        match super::parse_input_with_diagnostics("{A, B¹ab") {
+            Ok(_) => assert!(false),
+            Err(errors) => assert_eq!(errors.len(), 2),
+        }
+    }
+
     #[test]
     fn statement_set_is_consistent() {
         // Succeeds when statement set is consistent
@@ -384,9 +995,12 @@ mod tests {
                 'A',
                 Subscript(None),
             ))],
+            spans: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(statement_set.is_consistent().0, true);
+        assert_eq!(statement_set.is_consistent().0, Verdict::Yes);
 
         // Fails when statement set is not consistent
         let statement_set = StatementSet {
@@ -397,9 +1011,12 @@ mod tests {
                     Subscript(None),
                 )))),
             ],
+            spans: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(statement_set.is_consistent().0, false);
+        assert_eq!(statement_set.is_consistent().0, Verdict::No);
     }
 
     #[test]
@@ -411,9 +1028,12 @@ mod tests {
                 Subscript(None),
             ))],
             conclusion: Statement::Simple(SimpleStatementLetter('A', Subscript(None))),
+            spans: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(argument.is_valid().0, true);
+        assert_eq!(argument.is_valid().0, Verdict::Yes);
 
         // Fails when argument is invalid
         let argument = Argument {
@@ -422,9 +1042,12 @@ mod tests {
                 Subscript(None),
             ))],
             conclusion: Statement::Simple(SimpleStatementLetter('B', Subscript(None))),
+            spans: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(argument.is_valid().0, false);
+        assert_eq!(argument.is_valid().0, Verdict::No);
     }
 
     #[test]
@@ -440,9 +1063,12 @@ mod tests {
                     SimpleStatementLetter('A', Subscript(None)),
                 )))),
             ),
+            span: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(single_statement.is_contradiction().0, true);
+        assert_eq!(single_statement.is_contradiction().0, Verdict::Yes);
 
         // Fails when statement is not a contradiction
         let single_statement = SingleStatement {
@@ -456,9 +1082,12 @@ mod tests {
                     Subscript(None),
                 ))),
             ),
+            span: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(single_statement.is_contradiction().0, false);
+        assert_eq!(single_statement.is_contradiction().0, Verdict::No);
     }
 
     #[test]
@@ -474,16 +1103,22 @@ mod tests {
                     SimpleStatementLetter('A', Subscript(None)),
                 )))),
             ),
+            span: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(single_statement.is_tautology().0, true);
+        assert_eq!(single_statement.is_tautology().0, Verdict::Yes);
 
         // Fails when statement is not a tautology
         let single_statement = SingleStatement {
             statement: Statement::Simple(SimpleStatementLetter('A', Subscript(None))),
+            span: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(single_statement.is_tautology().0, false);
+        assert_eq!(single_statement.is_tautology().0, Verdict::No);
     }
 
     #[test]
@@ -491,9 +1126,12 @@ mod tests {
         // Succeeds when statement is contingent
         let single_statement = SingleStatement {
             statement: Statement::Simple(SimpleStatementLetter('A', Subscript(None))),
+            span: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
         };
 
-        assert_eq!(single_statement.is_contingency().0, true);
+        assert_eq!(single_statement.is_contingency().0, Verdict::Yes);
 
         // Fails when statement is not contingent
         let single_statement = SingleStatement {
@@ -506,8 +1144,195 @@ mod tests {
                     SimpleStatementLetter('A', Subscript(None)),
                 )))),
             ),
+            span: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
+        };
+
+        assert_eq!(single_statement.is_contingency().0, Verdict::No);
+    }
+
+    #[test]
+    fn infinite_tree_is_unknown_with_limits() {
+        // {(∀x)(∃y)L²xy} leads to an infinite tree
+        let statement_set = || match super::parse_input("{(∀x)(∃y)L²xy}") {
+            Ok(InputKind::StatementSet(statement_set)) => statement_set,
+            _ => unreachable!(),
+        };
+
+        let (is_consistent, truth_tree) = statement_set()
+            .with_limits(Limits {
+                max_universal_instantiations: Some(5),
+                ..Limits::default()
+            })
+            .is_consistent();
+
+        assert_eq!(
+            is_consistent,
+            Verdict::Unknown(Interruption::UniversalInstantiationLimitReached)
+        );
+        assert!(!truth_tree.is_complete());
+
+        let (is_consistent, truth_tree) = statement_set()
+            .with_limits(Limits {
+                max_nodes: Some(20),
+                ..Limits::default()
+            })
+            .is_consistent();
+
+        assert_eq!(
+            is_consistent,
+            Verdict::Unknown(Interruption::NodeLimitReached)
+        );
+        assert_eq!(truth_tree.interruption(), Some(&Interruption::NodeLimitReached));
+    }
+
+    #[test]
+    fn infinite_tree_is_unknown_when_cancelled() {
+        let token = CancellationToken::new();
+
+        let statement_set = match super::parse_input("{(∀x)(∃y)L²xy}") {
+            Ok(InputKind::StatementSet(statement_set)) => {
+                statement_set.with_cancellation(token.clone())
+            }
+            _ => unreachable!(),
         };
 
-        assert_eq!(single_statement.is_contingency().0, false);
+        let computation = std::thread::spawn(move || statement_set.is_consistent().0);
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        token.cancel();
+
+        assert_eq!(
+            computation.join().unwrap(),
+            Verdict::Unknown(Interruption::Cancelled)
+        );
+    }
+
+    #[test]
+    fn limits_do_not_change_finite_trees() {
+        let single_statement = SingleStatement {
+            statement: Statement::Simple(SimpleStatementLetter('A', Subscript(None))),
+            span: None,
+            limits: Limits::default(),
+            cancellation: CancellationToken::new(),
+        }
+        .with_limits(Limits {
+            max_nodes: Some(100),
+            max_branch_depth: Some(100),
+            max_universal_instantiations: Some(100),
+            time_budget: Some(std::time::Duration::from_secs(60)),
+        });
+
+        assert_eq!(single_statement.is_contingency().0, Verdict::Yes);
+    }
+
+    #[test]
+    fn equivalence_is_equivalent() {
+        // Succeeds when statements are equivalent
+        match super::parse_input("~(A & B) ⟚ (~A ∨ ~B)") {
+            Ok(InputKind::Equivalence(equivalence)) => {
+                let (is_equivalent, lhs_to_rhs, rhs_to_lhs, countermodel) =
+                    equivalence.is_equivalent();
+
+                assert_eq!(is_equivalent, Verdict::Yes);
+                assert!(!lhs_to_rhs.is_open() && !rhs_to_lhs.is_open());
+                assert!(countermodel.is_none());
+                assert_eq!(equivalence.rhs_span().unwrap().span.start, 13);
+            }
+            _ => assert!(false),
+        }
+
+        // Fails when one statement doesn't follow from the other
+        let a = Statement::Simple(SimpleStatementLetter('A', Subscript(None)));
+        let b = Statement::Simple(SimpleStatementLetter('B', Subscript(None)));
+        let equivalence = Equivalence::new(
+            Statement::LogicalConjunction(Box::new(a.clone()), Box::new(b)),
+            a,
+        )
+        .unwrap();
+
+        let (is_equivalent, lhs_to_rhs, rhs_to_lhs, countermodel) = equivalence.is_equivalent();
+
+        assert_eq!(is_equivalent, Verdict::No);
+        assert!(!lhs_to_rhs.is_open() && rhs_to_lhs.is_open());
+
+        let countermodel = countermodel.unwrap();
+        assert_eq!(
+            countermodel.truth_value(&SimpleStatementLetter('A', Subscript(None))),
+            Some(true)
+        );
+        assert_eq!(
+            countermodel.truth_value(&SimpleStatementLetter('B', Subscript(None))),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn truth_tables_agree_with_truth_trees() {
+        for input in &["{(A ⊃ B), ~B, A}", "{(A ∨ B), ~A}", "{(A ≡ ~A)}"] {
+            match super::parse_input(input) {
+                Ok(InputKind::StatementSet(statement_set)) => {
+                    let truth_table = statement_set.truth_table().unwrap();
+
+                    assert_eq!(
+                        truth_table.highlighted_rows().next().is_some(),
+                        statement_set.is_consistent().0 == Verdict::Yes
+                    );
+                }
+                _ => assert!(false),
+            }
+        }
+
+        for input in &["(A ⊃ B), A ∴ B", "(A ⊃ B), B ∴ A", "(A ≡ B), ~A ∴ ~B"] {
+            match super::parse_input(input) {
+                Ok(InputKind::Argument(argument)) => {
+                    let truth_table = argument.truth_table().unwrap();
+
+                    assert_eq!(
+                        truth_table.highlighted_rows().next().is_none(),
+                        argument.is_valid().0 == Verdict::Yes
+                    );
+                }
+                _ => assert!(false),
+            }
+        }
+
+        for input in &["(A ∨ ~A)", "(A & ~A)", "((A ⊃ B) ∨ C)"] {
+            match super::parse_input(input) {
+                Ok(InputKind::Statement(single_statement)) => {
+                    let truth_table = single_statement.truth_table().unwrap();
+                    let rows = truth_table.rows();
+
+                    assert_eq!(
+                        rows.iter().all(|x| !x.highlighted),
+                        single_statement.is_tautology().0 == Verdict::Yes
+                    );
+                    assert_eq!(
+                        rows.iter().all(|x| x.highlighted),
+                        single_statement.is_contradiction().0 == Verdict::Yes
+                    );
+                }
+                _ => assert!(false),
+            }
+        }
+
+        // Counterexamples of an argument are the rows where the premises are true and the
+        // conclusion false
+        match super::parse_input("(A ⊃ B), B ∴ A") {
+            Ok(InputKind::Argument(argument)) => {
+                let truth_table = argument.truth_table().unwrap();
+                let counterexamples = truth_table.highlighted_rows().collect::<Vec<_>>();
+
+                assert_eq!(counterexamples.len(), 1);
+                assert_eq!(counterexamples[0].truth_values, vec![false, true]);
+            }
+            _ => assert!(false),
+        }
+
+        match super::parse_input("F¹a ∴ A") {
+            Ok(InputKind::Argument(argument)) => assert!(argument.truth_table().is_none()),
+            _ => assert!(false),
+        }
     }
 }
@@ -0,0 +1,599 @@
+//! Conversions of statements into normal forms.
+//!
+//! ```
+//! use logic_rs::normal_form::{conjunctive_normal_form, prenex_normal_form};
+//! use logic_rs::{parse_input, InputKind};
+//!
+//! match parse_input("(A ∨ (B & ~(C ⊃ D)))") {
+//!     Ok(InputKind::Statement(st)) => assert_eq!(
+//!         conjunctive_normal_form(st.statement()).to_string(),
+//!         "((A ∨ B) & ((A ∨ C) & (A ∨ ~D)))"
+//!     ),
+//!     _ => assert!(false),
+//! }
+//!
+//! match parse_input("(∀xF¹x ⊃ ∀xG¹x)") {
+//!     Ok(InputKind::Statement(st)) => assert_eq!(
+//!         prenex_normal_form(st.statement()).to_string(),
+//!         "∃x∀y(~F¹x ∨ G¹y)"
+//!     ),
+//!     _ => assert!(false),
+//! }
+//! ```
+//!
+//! Every conversion first puts the statement into negation normal form, i.e. conditionals
+//! and biconditionals are rewritten in terms of negations, conjunctions and disjunctions,
+//! and negations are moved inwards until they only apply to simple statements, predicates
+//! and identities. Nothing is simplified beyond that (e.g. `(A & ~A)` is left as is).
+//!
+//! The statements returned have the same shape the parser gives them, so they can be
+//! compared with parsed statements.
+
+use crate::builder::{into_statement, Expr, Node};
+use crate::parser::{FunctionSymbol, SingularTerm, Statement, Subscript, Term, Variable};
+use crate::validity::first_unused_in_singular_term_stack;
+use crate::visit::{fold_term, Fold, Visit};
+use std::iter::once;
+
+/// Returns `statement` in negation normal form (see the [module documentation](index.html)).
+///
+/// A biconditional `(A ≡ B)` becomes `((~A ∨ B) & (~B ∨ A))`, and its negation
+/// `((A & ~B) ∨ (~A & B))`.
+pub fn negation_normal_form(statement: &Statement) -> Statement {
+    build(negation_normal_form_of(from(statement), false))
+}
+
+/// Returns `statement` in conjunctive normal form, i.e. as a conjunction of disjunctions.
+///
+/// Quantified statements are left in place, as if they were simple statements, but their
+/// formulas are in turn put into conjunctive normal form.
+pub fn conjunctive_normal_form(statement: &Statement) -> Statement {
+    build(conjunctive_normal_form_of(negation_normal_form_of(
+        from(statement),
+        false,
+    )))
+}
+
+/// Returns `statement` in disjunctive normal form, i.e. as a disjunction of conjunctions.
+///
+/// Quantified statements are left in place, as if they were simple statements, but their
+/// formulas are in turn put into disjunctive normal form.
+pub fn disjunctive_normal_form(statement: &Statement) -> Statement {
+    build(disjunctive_normal_form_of(negation_normal_form_of(
+        from(statement),
+        false,
+    )))
+}
+
+/// Returns `statement` in prenex normal form, i.e. with every quantifier at the front,
+/// followed by a formula in negation normal form without quantifiers.
+///
+/// Quantifiers are moved out of their scopes from left to right. Since no two quantifiers
+/// in front of a formula may bind the same variable, those that bind a variable that is
+/// already bound by some quantifier before them are given the first variable that doesn't
+/// appear anywhere in the statement (`x`, `y`, `z`, then `x₁`, `y₁`, `z₁`, etc.).
+pub fn prenex_normal_form(statement: &Statement) -> Statement {
+    let (prefix, matrix) = prenex_normal_form_of(statement);
+
+    build(quantify(prefix, matrix))
+}
+
+/// Skolemizes each statement of a set, i.e. puts it into [prenex normal form](fn.prenex_normal_form.html)
+/// and then removes every existential quantifier, replacing the variable it binds with a new term.
+///
+/// If the existential quantifier isn't in the scope of any universal quantifier, that term is
+/// a new singular term (a Skolem constant), picked just as the truth tree method picks the
+/// singular term to instantiate an existential statement to: the first of `a`, `b`, ..., `w`,
+/// `a₁`, `b₁`, etc. that doesn't appear anywhere in the set. Otherwise, it is a new function
+/// symbol (the first of `f`, `g`, `h`, `f₁`, `g₁`, etc. that doesn't appear anywhere in the set)
+/// applied to the variables bound by those universal quantifiers, e.g. `∀x∃yL²xy` becomes
+/// `∀xL²xf(x)`: which object there is depends on which object `x` is.
+///
+/// The statements returned are consistent if and only if the statements Skolemized are, but
+/// they aren't equivalent. Function terms can't be used in truth trees (see [Term](../enum.Term.html)),
+/// but see the [herbrand](../herbrand/index.html) module.
+pub fn skolemize(statements: &[Statement]) -> Vec<Statement> {
+    let mut symbols = Symbols::default();
+
+    for statement in statements {
+        symbols.visit_statement(statement);
+    }
+
+    statements
+        .iter()
+        .map(|statement| {
+            let (prefix, mut matrix) = prenex_normal_form_of(statement);
+            let mut universal_variables = vec![];
+
+            for (is_universal, variable) in prefix {
+                if is_universal {
+                    universal_variables.push(variable);
+                    continue;
+                }
+
+                let term = if universal_variables.is_empty() {
+                    let singular_term =
+                        first_unused_in_singular_term_stack(&symbols.singular_terms);
+                    symbols.singular_terms.push(singular_term.clone());
+
+                    Term::SingularTerm(singular_term)
+                } else {
+                    let function_symbol = first_unused_function_symbol(&symbols.function_symbols);
+                    symbols.function_symbols.push(function_symbol.clone());
+
+                    Term::Function(
+                        function_symbol,
+                        universal_variables
+                            .iter()
+                            .cloned()
+                            .map(Term::Variable)
+                            .collect(),
+                    )
+                };
+
+                matrix = replace_variable(matrix, &variable, &term);
+            }
+
+            build(quantify(
+                universal_variables.into_iter().map(|x| (true, x)).collect(),
+                matrix,
+            ))
+        })
+        .collect()
+}
+
+// Returns the quantifiers of the prenex normal form of 'statement' (as whether each is universal,
+// and the variable it binds), in order, and the formula that follows them
+fn prenex_normal_form_of(statement: &Statement) -> (Vec<(bool, Variable)>, Node) {
+    let mut symbols = Symbols::default();
+    symbols.visit_statement(statement);
+
+    let mut prefix = vec![];
+    let matrix = move_quantifiers_out(
+        negation_normal_form_of(from(statement), false),
+        &mut prefix,
+        &mut symbols.variables,
+    );
+
+    (prefix, matrix)
+}
+
+fn quantify(prefix: Vec<(bool, Variable)>, matrix: Node) -> Node {
+    prefix
+        .into_iter()
+        .rev()
+        .fold(matrix, |node, (is_universal, variable)| {
+            if is_universal {
+                Node::Universal(variable, Box::new(node))
+            } else {
+                Node::Existential(variable, Box::new(node))
+            }
+        })
+}
+
+fn from(statement: &Statement) -> Node {
+    Expr::from(statement.clone()).0
+}
+
+fn build(node: Node) -> Statement {
+    // The conversions never move a variable out of the scope of its quantifier
+    into_statement(node).expect("variable out of scope in normal form")
+}
+
+// Returns the negation normal form of 'node', or of its negation if 'negated' is true
+fn negation_normal_form_of(node: Node, negated: bool) -> Node {
+    match node {
+        Node::Simple(_) | Node::Predicate(_, _) | Node::Identity(_, _) => {
+            if negated {
+                Node::Negation(Box::new(node))
+            } else {
+                node
+            }
+        }
+        Node::Negation(rnode) => negation_normal_form_of(*rnode, !negated),
+        Node::Conjunction(lnode, rnode) => {
+            let lnode = Box::new(negation_normal_form_of(*lnode, negated));
+            let rnode = Box::new(negation_normal_form_of(*rnode, negated));
+
+            if negated {
+                Node::Disjunction(lnode, rnode)
+            } else {
+                Node::Conjunction(lnode, rnode)
+            }
+        }
+        Node::Disjunction(lnode, rnode) => {
+            let lnode = Box::new(negation_normal_form_of(*lnode, negated));
+            let rnode = Box::new(negation_normal_form_of(*rnode, negated));
+
+            if negated {
+                Node::Conjunction(lnode, rnode)
+            } else {
+                Node::Disjunction(lnode, rnode)
+            }
+        }
+        // (A ⊃ B) is (~A ∨ B)
+        Node::Conditional(lnode, rnode) => negation_normal_form_of(
+            Node::Disjunction(Box::new(Node::Negation(lnode)), rnode),
+            negated,
+        ),
+        Node::Biconditional(lnode, rnode) => {
+            if negated {
+                // ((A & ~B) ∨ (~A & B))
+                Node::Disjunction(
+                    Box::new(Node::Conjunction(
+                        Box::new(negation_normal_form_of(*lnode.clone(), false)),
+                        Box::new(negation_normal_form_of(*rnode.clone(), true)),
+                    )),
+                    Box::new(Node::Conjunction(
+                        Box::new(negation_normal_form_of(*lnode, true)),
+                        Box::new(negation_normal_form_of(*rnode, false)),
+                    )),
+                )
+            } else {
+                // ((~A ∨ B) & (~B ∨ A))
+                Node::Conjunction(
+                    Box::new(Node::Disjunction(
+                        Box::new(negation_normal_form_of(*lnode.clone(), true)),
+                        Box::new(negation_normal_form_of(*rnode.clone(), false)),
+                    )),
+                    Box::new(Node::Disjunction(
+                        Box::new(negation_normal_form_of(*rnode, true)),
+                        Box::new(negation_normal_form_of(*lnode, false)),
+                    )),
+                )
+            }
+        }
+        Node::Existential(variable, rnode) => {
+            let rnode = Box::new(negation_normal_form_of(*rnode, negated));
+
+            if negated {
+                Node::Universal(variable, rnode)
+            } else {
+                Node::Existential(variable, rnode)
+            }
+        }
+        Node::Universal(variable, rnode) => {
+            let rnode = Box::new(negation_normal_form_of(*rnode, negated));
+
+            if negated {
+                Node::Existential(variable, rnode)
+            } else {
+                Node::Universal(variable, rnode)
+            }
+        }
+    }
+}
+
+// The functions below all take nodes in negation normal form
+
+fn conjunctive_normal_form_of(node: Node) -> Node {
+    match node {
+        Node::Conjunction(lnode, rnode) => Node::Conjunction(
+            Box::new(conjunctive_normal_form_of(*lnode)),
+            Box::new(conjunctive_normal_form_of(*rnode)),
+        ),
+        Node::Disjunction(lnode, rnode) => distribute_disjunction(
+            conjunctive_normal_form_of(*lnode),
+            conjunctive_normal_form_of(*rnode),
+        ),
+        Node::Existential(variable, rnode) => {
+            Node::Existential(variable, Box::new(conjunctive_normal_form_of(*rnode)))
+        }
+        Node::Universal(variable, rnode) => {
+            Node::Universal(variable, Box::new(conjunctive_normal_form_of(*rnode)))
+        }
+        node => node,
+    }
+}
+
+// Returns (lnode ∨ rnode), both in conjunctive normal form, in conjunctive normal form
+fn distribute_disjunction(lnode: Node, rnode: Node) -> Node {
+    match (lnode, rnode) {
+        // ((A & B) ∨ C) is ((A ∨ C) & (B ∨ C))
+        (Node::Conjunction(lnode1, lnode2), rnode) => Node::Conjunction(
+            Box::new(distribute_disjunction(*lnode1, rnode.clone())),
+            Box::new(distribute_disjunction(*lnode2, rnode)),
+        ),
+        (lnode, Node::Conjunction(rnode1, rnode2)) => Node::Conjunction(
+            Box::new(distribute_disjunction(lnode.clone(), *rnode1)),
+            Box::new(distribute_disjunction(lnode, *rnode2)),
+        ),
+        (lnode, rnode) => Node::Disjunction(Box::new(lnode), Box::new(rnode)),
+    }
+}
+
+fn disjunctive_normal_form_of(node: Node) -> Node {
+    match node {
+        Node::Disjunction(lnode, rnode) => Node::Disjunction(
+            Box::new(disjunctive_normal_form_of(*lnode)),
+            Box::new(disjunctive_normal_form_of(*rnode)),
+        ),
+        Node::Conjunction(lnode, rnode) => distribute_conjunction(
+            disjunctive_normal_form_of(*lnode),
+            disjunctive_normal_form_of(*rnode),
+        ),
+        Node::Existential(variable, rnode) => {
+            Node::Existential(variable, Box::new(disjunctive_normal_form_of(*rnode)))
+        }
+        Node::Universal(variable, rnode) => {
+            Node::Universal(variable, Box::new(disjunctive_normal_form_of(*rnode)))
+        }
+        node => node,
+    }
+}
+
+// Returns (lnode & rnode), both in disjunctive normal form, in disjunctive normal form
+fn distribute_conjunction(lnode: Node, rnode: Node) -> Node {
+    match (lnode, rnode) {
+        // ((A ∨ B) & C) is ((A & C) ∨ (B & C))
+        (Node::Disjunction(lnode1, lnode2), rnode) => Node::Disjunction(
+            Box::new(distribute_conjunction(*lnode1, rnode.clone())),
+            Box::new(distribute_conjunction(*lnode2, rnode)),
+        ),
+        (lnode, Node::Disjunction(rnode1, rnode2)) => Node::Disjunction(
+            Box::new(distribute_conjunction(lnode.clone(), *rnode1)),
+            Box::new(distribute_conjunction(lnode, *rnode2)),
+        ),
+        (lnode, rnode) => Node::Conjunction(Box::new(lnode), Box::new(rnode)),
+    }
+}
+
+// Moves every quantifier in 'node' into 'prefix' (as whether it's universal, and the variable
+// it binds), in order, and returns what's left. Since the node is in negation normal form,
+// no quantifier is within the scope of a negation, and so none changes when moved out.
+fn move_quantifiers_out(
+    node: Node,
+    prefix: &mut Vec<(bool, Variable)>,
+    used_variables: &mut Vec<Variable>,
+) -> Node {
+    match node {
+        Node::Conjunction(lnode, rnode) => Node::Conjunction(
+            Box::new(move_quantifiers_out(*lnode, prefix, used_variables)),
+            Box::new(move_quantifiers_out(*rnode, prefix, used_variables)),
+        ),
+        Node::Disjunction(lnode, rnode) => Node::Disjunction(
+            Box::new(move_quantifiers_out(*lnode, prefix, used_variables)),
+            Box::new(move_quantifiers_out(*rnode, prefix, used_variables)),
+        ),
+        Node::Existential(variable, rnode) => {
+            move_quantifier_out(false, variable, *rnode, prefix, used_variables)
+        }
+        Node::Universal(variable, rnode) => {
+            move_quantifier_out(true, variable, *rnode, prefix, used_variables)
+        }
+        node => node,
+    }
+}
+
+fn move_quantifier_out(
+    is_universal: bool,
+    variable: Variable,
+    node: Node,
+    prefix: &mut Vec<(bool, Variable)>,
+    used_variables: &mut Vec<Variable>,
+) -> Node {
+    // A variable can't be bound again within the scope of its quantifier, so the
+    // only quantifiers in 'prefix' that may bind the same variable are those moved
+    // out of some other part of the statement
+    let (variable, node) = if prefix.iter().any(|(_, x)| *x == variable) {
+        let new_variable = first_unused_variable(used_variables);
+        used_variables.push(new_variable.clone());
+
+        let node = replace_variable(node, &variable, &Term::Variable(new_variable.clone()));
+
+        (new_variable, node)
+    } else {
+        (variable, node)
+    };
+
+    prefix.push((is_universal, variable));
+
+    move_quantifiers_out(node, prefix, used_variables)
+}
+
+// Replaces every occurrence of 'variable' in 'node' with 'term'
+pub(crate) fn replace_variable(node: Node, variable: &Variable, term: &Term) -> Node {
+    let replace_term = |x: Term| VariableReplacer { variable, term }.fold_term(x);
+    let replace = |node: Box<Node>| Box::new(replace_variable(*node, variable, term));
+
+    match node {
+        Node::Simple(_) => node,
+        Node::Predicate(predicate_letter, terms) => Node::Predicate(
+            predicate_letter,
+            terms.into_iter().map(replace_term).collect(),
+        ),
+        Node::Identity(lterm, rterm) => Node::Identity(replace_term(lterm), replace_term(rterm)),
+        Node::Negation(rnode) => Node::Negation(replace(rnode)),
+        Node::Conjunction(lnode, rnode) => Node::Conjunction(replace(lnode), replace(rnode)),
+        Node::Disjunction(lnode, rnode) => Node::Disjunction(replace(lnode), replace(rnode)),
+        Node::Conditional(lnode, rnode) => Node::Conditional(replace(lnode), replace(rnode)),
+        Node::Biconditional(lnode, rnode) => Node::Biconditional(replace(lnode), replace(rnode)),
+        // Quantifiers within the scope of the one that binds 'variable' can't bind it again
+        Node::Existential(x, rnode) => Node::Existential(x, replace(rnode)),
+        Node::Universal(x, rnode) => Node::Universal(x, replace(rnode)),
+    }
+}
+
+struct VariableReplacer<'a> {
+    variable: &'a Variable,
+    term: &'a Term,
+}
+
+impl<'a> Fold for VariableReplacer<'a> {
+    fn fold_term(&mut self, term: Term) -> Term {
+        match term {
+            Term::Variable(ref x) if x == self.variable => self.term.clone(),
+            term => fold_term(self, term),
+        }
+    }
+}
+
+fn first_unused_variable(used_variables: &[Variable]) -> Variable {
+    // Same order as in first_unused_in_singular_term_stack
+    for subscript in once(Subscript(None)).chain((1..).map(|x| Subscript(Some(x)))) {
+        for c in b'x'..=b'z' {
+            let variable = Variable(c as char, subscript.clone());
+            if !used_variables.contains(&variable) {
+                return variable;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+fn first_unused_function_symbol(used_function_symbols: &[FunctionSymbol]) -> FunctionSymbol {
+    for subscript in once(Subscript(None)).chain((1..).map(|x| Subscript(Some(x)))) {
+        for c in b'f'..=b'h' {
+            let function_symbol = FunctionSymbol(c as char, subscript.clone());
+            if !used_function_symbols.contains(&function_symbol) {
+                return function_symbol;
+            }
+        }
+    }
+
+    unreachable!()
+}
+
+// Every variable, singular term and function symbol that appears in some statements,
+// in order of appearance
+#[derive(Default)]
+struct Symbols {
+    variables: Vec<Variable>,
+    singular_terms: Vec<SingularTerm>,
+    function_symbols: Vec<FunctionSymbol>,
+}
+
+impl Visit for Symbols {
+    fn visit_variable(&mut self, variable: &Variable) {
+        if !self.variables.contains(variable) {
+            self.variables.push(variable.clone());
+        }
+    }
+
+    fn visit_singular_term(&mut self, singular_term: &SingularTerm) {
+        if !self.singular_terms.contains(singular_term) {
+            self.singular_terms.push(singular_term.clone());
+        }
+    }
+
+    fn visit_function_symbol(&mut self, function_symbol: &FunctionSymbol) {
+        if !self.function_symbols.contains(function_symbol) {
+            self.function_symbols.push(function_symbol.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::not;
+    use crate::parser::{parse_statement, parse_statements};
+    use crate::validity::TruthTreeMethod;
+
+    // Checks that ~((lhs ⊃ rhs) & (rhs ⊃ lhs)) is a contradiction
+    fn assert_equivalent(lhs: &Statement, rhs: &Statement) {
+        let lexpr = Expr::from(lhs.clone());
+        let rexpr = Expr::from(rhs.clone());
+
+        let statement = not(lexpr
+            .clone()
+            .implies(rexpr.clone())
+            .and(rexpr.implies(lexpr)))
+        .build()
+        .unwrap();
+
+        assert!(
+            !TruthTreeMethod::new(&vec![statement]).compute().is_open(),
+            "{} is not equivalent to {}",
+            lhs,
+            rhs
+        );
+    }
+
+    fn check(convert: fn(&Statement) -> Statement, input: &str, expected: &str) {
+        let statement = parse_statement(input);
+        let converted = convert(&statement);
+
+        assert_eq!(converted, parse_statement(expected));
+        assert_equivalent(&statement, &converted);
+    }
+
+    #[test]
+    fn negation_normal_form_of_statements() {
+        check(negation_normal_form, "~(A & ~(B ∨ C))", "(~A ∨ (B ∨ C))");
+        check(negation_normal_form, "(A ⊃ ~B)", "(~A ∨ ~B)");
+        check(negation_normal_form, "(A ≡ B)", "((~A ∨ B) & (~B ∨ A))");
+        check(negation_normal_form, "~(A ≡ B)", "((A & ~B) ∨ (~A & B))");
+        check(
+            negation_normal_form,
+            "~∀x(F¹x ⊃ ∃y~R²xy)",
+            "∃x(F¹x & ∀yR²xy)",
+        );
+        check(negation_normal_form, "~∃x~x = a", "∀xx = a");
+    }
+
+    #[test]
+    fn conjunctive_normal_form_of_statements() {
+        check(
+            conjunctive_normal_form,
+            "(A ∨ (B & C))",
+            "((A ∨ B) & (A ∨ C))",
+        );
+        check(
+            conjunctive_normal_form,
+            "((A & B) ∨ (C & D))",
+            "(((A ∨ C) & (A ∨ D)) & ((B ∨ C) & (B ∨ D)))",
+        );
+        check(
+            conjunctive_normal_form,
+            "∀x(F¹x ∨ (G¹x & ~A))",
+            "∀x((F¹x ∨ G¹x) & (F¹x ∨ ~A))",
+        );
+    }
+
+    #[test]
+    fn disjunctive_normal_form_of_statements() {
+        check(
+            disjunctive_normal_form,
+            "(A & (B ∨ C))",
+            "((A & B) ∨ (A & C))",
+        );
+        check(
+            disjunctive_normal_form,
+            "~(A ⊃ (B & C))",
+            "((A & ~B) ∨ (A & ~C))",
+        );
+        check(
+            disjunctive_normal_form,
+            "(A ≡ B)",
+            "(((~A & ~B) ∨ (~A & A)) ∨ ((B & ~B) ∨ (B & A)))",
+        );
+    }
+
+    #[test]
+    fn prenex_normal_form_of_statements() {
+        check(prenex_normal_form, "(∀xF¹x ⊃ ∃yG¹y)", "∃x∃y(~F¹x ∨ G¹y)");
+        check(prenex_normal_form, "(∀xF¹x & ∃xG¹x)", "∀x∃y(F¹x & G¹y)");
+        check(
+            prenex_normal_form,
+            "(∃xF¹x ∨ (∃xG¹x & ∃yH¹y))",
+            "∃x∃z∃y(F¹x ∨ (G¹z & H¹y))",
+        );
+        check(prenex_normal_form, "∃x(F¹x & ~∃yR²yx)", "∃x∀y(F¹x & ~R²yx)");
+        check(prenex_normal_form, "(A & ~B)", "(A & ~B)");
+    }
+
+    #[test]
+    fn skolemized_statements() {
+        let statements = parse_statements("{∀x∃yL²xy, ∃xF¹x, ∀x∀y∃zR³xyz, ∃x∀yL²xy}");
+
+        assert_eq!(
+            skolemize(&statements)
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>(),
+            vec!["∀xL²xf(x)", "F¹a", "∀x∀yR³xyg(xy)", "∀yL²by"]
+        );
+    }
+}
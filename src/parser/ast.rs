@@ -2,7 +2,7 @@
 /// statement letter, simple predicate letter, or term.
 /// 
 /// **Serialization of this struct requires the feature `serde_support` to be enabled.**
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub struct Subscript(pub Option<u64>);
 
@@ -18,21 +18,21 @@ impl PartialEq<u64> for Subscript {
 /// The identifier of a simple statement.
 /// 
 /// **Serialization of this struct requires the feature `serde_support` to be enabled.**
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub struct SimpleStatementLetter(pub char, pub Subscript);
 
 /// The identifier of a singular term.
 /// 
 /// **Serialization of this struct requires the feature `serde_support` to be enabled.**
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub struct SingularTerm(pub char, pub Subscript);
 
 /// The identifier of a variable.
 /// 
 /// **Serialization of this struct requires the feature `serde_support` to be enabled.**
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub struct Variable(pub char, pub Subscript);
 
@@ -40,7 +40,7 @@ pub struct Variable(pub char, pub Subscript);
 /// attached) of a simple predicate.
 /// 
 /// **Serialization of this struct requires the feature `serde_support` to be enabled.**
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub struct Degree(pub u64);
 
@@ -53,56 +53,127 @@ impl PartialEq<u64> for Degree {
 /// The identifier of a simple predicate.
 /// 
 /// **Serialization of this struct requires the feature `serde_support` to be enabled.**
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub struct PredicateLetter(pub char, pub Subscript, pub Degree);
 
+/// The identifier of a function symbol.
+/// 
+/// **Serialization of this struct requires the feature `serde_support` to be enabled.**
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
+pub struct FunctionSymbol(pub char, pub Subscript);
+
 /// A term.
 /// 
+/// Function terms are not part of the language of the book, and so are never produced
+/// by the parser. They are only made by [Skolemization](normal_form/fn.skolemize.html),
+/// and can't be used in a [StatementSet](struct.StatementSet.html), an [Argument](struct.Argument.html)
+/// an [Equivalence](struct.Equivalence.html) or a [SingleStatement](struct.SingleStatement.html),
+/// nor in truth trees (see [TruthTreeMethod::new](struct.TruthTreeMethod.html#method.new)).
+/// 
 /// **Serialization of this enum requires the feature `serde_support` to be enabled.**
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub enum Term {
     SingularTerm(SingularTerm),
     Variable(Variable),
+    /// A function symbol applied to some terms, e.g. `f(xa)`.
+    Function(FunctionSymbol, Vec<Term>),
+}
+
+impl From<SingularTerm> for Term {
+    fn from(singular_term: SingularTerm) -> Self {
+        Term::SingularTerm(singular_term)
+    }
+}
+
+impl From<Variable> for Term {
+    fn from(variable: Variable) -> Self {
+        Term::Variable(variable)
+    }
 }
 
 /// A statement.
 /// 
 /// **Serialization of this enum requires the feature `serde_support` to be enabled.**
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub enum Statement {
     Simple(SimpleStatementLetter),
     Singular(PredicateLetter, Vec<SingularTerm>),
+    Identity(SingularTerm, SingularTerm),
     LogicalConjunction(Box<Statement>, Box<Statement>),
     LogicalNegation(Box<Statement>),
     LogicalDisjunction(Box<Statement>, Box<Statement>),
     LogicalConditional(Box<Statement>, Box<Statement>),
+    LogicalBiconditional(Box<Statement>, Box<Statement>),
     Existential(Variable, Box<Formula>),
     Universal(Variable, Box<Formula>),
 }
 
-/// A formula. It can be any statement, predicate, or a compound formula made of either of these.
+/// A formula. It can be any statement, predicate, identity, or a compound formula made of either of these.
 /// 
 /// **Serialization of this enum requires the feature `serde_support` to be enabled.**
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub enum Formula {
     Statement(Box<Statement>),
     Predicate(PredicateLetter, Vec<Term>),
+    Identity(Term, Term),
     Conjunction(Box<Formula>, Box<Formula>),
     Negation(Box<Formula>),
     Disjunction(Box<Formula>, Box<Formula>),
     Conditional(Box<Formula>, Box<Formula>),
+    Biconditional(Box<Formula>, Box<Formula>),
+}
+
+/// Where some part of the input is.
+///
+/// **Serialization of this struct requires the feature `serde_support` to be enabled.**
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
+pub struct SourceSpan {
+    /// The byte offset of the start.
+    pub start: usize,
+    /// The byte offset right after the end.
+    pub end: usize,
+    /// (line, column) of the start.
+    pub start_location: (usize, usize),
+    /// (line, column) right after the end.
+    pub end_location: (usize, usize),
+}
+
+/// Where a parsed statement and each of its parts are in the input.
+///
+/// Statements and formulas don't keep track of where they were parsed from, so that
+/// equal statements are equal no matter where they are. Instead, a span tree mirrors
+/// the structure of a statement: `children` has a span tree for each field of the
+/// variant of [Statement](enum.Statement.html), [Formula](enum.Formula.html) or
+/// [Term](enum.Term.html) that `span` is of, in order (e.g. the predicate letter and
+/// then each term of `Statement::Singular`). Identifiers (e.g. simple statement letters
+/// and variables) have no children, and `Formula::Statement` has the same span tree as
+/// the statement it contains.
+///
+/// Grouping parentheses around a statement or formula that aren't part of its syntax
+/// (e.g. those around `A` in `~(A)`) aren't included in its span.
+///
+/// **Serialization of this struct requires the feature `serde_support` to be enabled.**
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
+pub struct SpanTree {
+    pub span: SourceSpan,
+    pub children: Vec<SpanTree>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Input {
     StatementSet(Vec<Statement>),
     Argument(Vec<Statement>, Statement),
+    Equivalence(Statement, Statement),
     Statement(Statement),
 }
 
+// The span trees are those of each statement of the input, in order
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct ParseTree(pub Input);
\ No newline at end of file
+pub struct ParseTree(pub Input, pub Vec<SpanTree>);
\ No newline at end of file
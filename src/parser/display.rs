@@ -0,0 +1,680 @@
+use super::ast::{
+    Degree, Formula, FunctionSymbol, PredicateLetter, SimpleStatementLetter, SingularTerm,
+    Statement, Subscript, Term, Variable,
+};
+use std::fmt;
+
+/// The symbols used to display statements and formulas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Notation {
+    /// The notation of the book, e.g. `∀x(F¹x ⊃ G¹x)`.
+    Unicode,
+    /// The ASCII alternative accepted by the parser, e.g. `Ax(F^1x -> G^1x)`.
+    Ascii,
+    /// LaTeX math mode, e.g. `\forall x(F^{1}x \supset G^{1}x)`. This is only meant
+    /// for typesetting, and isn't accepted by the parser.
+    Latex,
+}
+
+/// How many parentheses are used to display statements and formulas.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Parenthesization {
+    /// Only the parentheses the grammar requires, e.g. `~∀x~F¹x`.
+    Minimal,
+    /// Quantifiers are enclosed in parentheses, and so is the operand of
+    /// every negation and quantifier that isn't already enclosed in them,
+    /// e.g. `~((∀x)(~F¹x))`.
+    Full,
+}
+
+/// Options for displaying statements, formulas and terms.
+///
+/// The default, which is also what their `Display` implementation uses,
+/// is the notation of the book with minimal parenthesization.
+///
+/// Any statement produced by the parser, displayed in the Unicode or ASCII
+/// notation, is parsed back into the exact same statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayOptions {
+    pub notation: Notation,
+    pub parenthesization: Parenthesization,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> Self {
+        DisplayOptions {
+            notation: Notation::Unicode,
+            parenthesization: Parenthesization::Minimal,
+        }
+    }
+}
+
+struct Symbols {
+    negation: &'static str,
+    conjunction: &'static str,
+    disjunction: &'static str,
+    conditional: &'static str,
+    biconditional: &'static str,
+    identity: &'static str,
+    existential: &'static str,
+    universal: &'static str,
+}
+
+impl Notation {
+    fn symbols(&self) -> Symbols {
+        match self {
+            Notation::Unicode => Symbols {
+                negation: "~",
+                conjunction: "&",
+                disjunction: "\u{2228}",
+                conditional: "\u{2283}",
+                biconditional: "\u{2261}",
+                identity: "=",
+                existential: "\u{2203}",
+                universal: "\u{2200}",
+            },
+            Notation::Ascii => Symbols {
+                negation: "~",
+                conjunction: "&",
+                disjunction: "v",
+                conditional: "->",
+                biconditional: "<->",
+                identity: "=",
+                existential: "E",
+                universal: "A",
+            },
+            Notation::Latex => Symbols {
+                negation: "{\\sim}",
+                conjunction: "\\&",
+                disjunction: "\\lor",
+                conditional: "\\supset",
+                biconditional: "\\equiv",
+                identity: "=",
+                existential: "\\exists ",
+                universal: "\\forall ",
+            },
+        }
+    }
+
+    fn write_subscript(&self, f: &mut fmt::Formatter, n: u64) -> fmt::Result {
+        match self {
+            Notation::Unicode => write!(
+                f,
+                "{}",
+                n.to_string()
+                    .chars()
+                    .map(|x| match x {
+                        '0' => '\u{2080}',
+                        '1' => '\u{2081}',
+                        '2' => '\u{2082}',
+                        '3' => '\u{2083}',
+                        '4' => '\u{2084}',
+                        '5' => '\u{2085}',
+                        '6' => '\u{2086}',
+                        '7' => '\u{2087}',
+                        '8' => '\u{2088}',
+                        '9' => '\u{2089}',
+                        _ => unreachable!(),
+                    })
+                    .collect::<String>()
+            ),
+            Notation::Ascii => write!(f, "_{}", n),
+            Notation::Latex => write!(f, "_{{{}}}", n),
+        }
+    }
+
+    fn write_superscript(&self, f: &mut fmt::Formatter, n: u64) -> fmt::Result {
+        match self {
+            Notation::Unicode => write!(
+                f,
+                "{}",
+                n.to_string()
+                    .chars()
+                    .map(|x| match x {
+                        '0' => '\u{2070}',
+                        '1' => '\u{00B9}',
+                        '2' => '\u{00B2}',
+                        '3' => '\u{00B3}',
+                        '4' => '\u{2074}',
+                        '5' => '\u{2075}',
+                        '6' => '\u{2076}',
+                        '7' => '\u{2077}',
+                        '8' => '\u{2078}',
+                        '9' => '\u{2079}',
+                        _ => unreachable!(),
+                    })
+                    .collect::<String>()
+            ),
+            Notation::Ascii => write!(f, "^{}", n),
+            Notation::Latex => write!(f, "^{{{}}}", n),
+        }
+    }
+}
+
+trait WriteWithOptions {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result;
+}
+
+struct WithOptions<'a, T>(&'a T, DisplayOptions);
+
+impl<'a, T: WriteWithOptions> fmt::Display for WithOptions<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.write(f, &self.1)
+    }
+}
+
+impl WriteWithOptions for Subscript {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        match self.0 {
+            Some(n) => options.notation.write_subscript(f, n),
+            None => Ok(()),
+        }
+    }
+}
+
+impl WriteWithOptions for Degree {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        options.notation.write_superscript(f, self.0)
+    }
+}
+
+impl WriteWithOptions for SimpleStatementLetter {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        self.1.write(f, options)
+    }
+}
+
+impl WriteWithOptions for SingularTerm {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        self.1.write(f, options)
+    }
+}
+
+impl WriteWithOptions for Variable {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        self.1.write(f, options)
+    }
+}
+
+impl WriteWithOptions for PredicateLetter {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        self.1.write(f, options)?;
+        self.2.write(f, options)
+    }
+}
+
+impl WriteWithOptions for FunctionSymbol {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+        self.1.write(f, options)
+    }
+}
+
+impl WriteWithOptions for Term {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        match self {
+            Term::SingularTerm(ref t) => t.write(f, options),
+            Term::Variable(ref v) => v.write(f, options),
+            // Not part of the grammar, but written the same way terms follow a predicate letter
+            Term::Function(ref function_symbol, ref terms) => {
+                function_symbol.write(f, options)?;
+                write!(f, "(")?;
+
+                for term in terms {
+                    term.write(f, options)?;
+                }
+
+                write!(f, ")")
+            }
+        }
+    }
+}
+
+fn write_binary<T: WriteWithOptions>(
+    f: &mut fmt::Formatter,
+    options: &DisplayOptions,
+    lhs: &T,
+    connective: &str,
+    rhs: &T,
+) -> fmt::Result {
+    // The grammar requires binary connectives to always be enclosed in parentheses
+    write!(f, "(")?;
+    lhs.write(f, options)?;
+    write!(f, " {} ", connective)?;
+    rhs.write(f, options)?;
+    write!(f, ")")
+}
+
+fn write_quantifier(
+    f: &mut fmt::Formatter,
+    options: &DisplayOptions,
+    quantifier: &str,
+    var: &Variable,
+) -> fmt::Result {
+    match options.parenthesization {
+        Parenthesization::Minimal => {
+            write!(f, "{}", quantifier)?;
+            var.write(f, options)
+        }
+        Parenthesization::Full => {
+            write!(f, "({}", quantifier)?;
+            var.write(f, options)?;
+            write!(f, ")")
+        }
+    }
+}
+
+fn write_operand<T: WriteWithOptions>(
+    f: &mut fmt::Formatter,
+    options: &DisplayOptions,
+    operand: &T,
+    needs_parentheses: bool,
+) -> fmt::Result {
+    // Operand of a negation or quantifier
+    if needs_parentheses && options.parenthesization == Parenthesization::Full {
+        write!(f, "(")?;
+        operand.write(f, options)?;
+        write!(f, ")")
+    } else {
+        operand.write(f, options)
+    }
+}
+
+impl Statement {
+    fn is_negation_or_quantified(&self) -> bool {
+        match self {
+            Statement::LogicalNegation(_)
+            | Statement::Existential(_, _)
+            | Statement::Universal(_, _) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns an object that displays this statement as specified by `options`.
+    pub fn display_with(&self, options: DisplayOptions) -> impl fmt::Display + '_ {
+        WithOptions(self, options)
+    }
+}
+
+impl WriteWithOptions for Statement {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        let symbols = options.notation.symbols();
+
+        match self {
+            Statement::Simple(ref letter) => letter.write(f, options),
+            Statement::Singular(ref pred_letter, ref terms) => {
+                pred_letter.write(f, options)?;
+                terms.iter().try_for_each(|x| x.write(f, options))
+            }
+            Statement::Identity(ref lterm, ref rterm) => {
+                lterm.write(f, options)?;
+                write!(f, " {} ", symbols.identity)?;
+                rterm.write(f, options)
+            }
+            Statement::LogicalConjunction(ref lst, ref rst) => {
+                write_binary(f, options, &**lst, symbols.conjunction, &**rst)
+            }
+            Statement::LogicalNegation(ref rst) => {
+                write!(f, "{}", symbols.negation)?;
+                write_operand(f, options, &**rst, rst.is_negation_or_quantified())
+            }
+            Statement::LogicalDisjunction(ref lst, ref rst) => {
+                write_binary(f, options, &**lst, symbols.disjunction, &**rst)
+            }
+            Statement::LogicalConditional(ref lst, ref rst) => {
+                write_binary(f, options, &**lst, symbols.conditional, &**rst)
+            }
+            Statement::LogicalBiconditional(ref lst, ref rst) => {
+                write_binary(f, options, &**lst, symbols.biconditional, &**rst)
+            }
+            Statement::Existential(ref var, ref formula) => {
+                write_quantifier(f, options, symbols.existential, var)?;
+                write_operand(f, options, &**formula, formula.is_negation_or_quantified())
+            }
+            Statement::Universal(ref var, ref formula) => {
+                write_quantifier(f, options, symbols.universal, var)?;
+                write_operand(f, options, &**formula, formula.is_negation_or_quantified())
+            }
+        }
+    }
+}
+
+impl Formula {
+    fn is_negation_or_quantified(&self) -> bool {
+        match self {
+            Formula::Negation(_) => true,
+            Formula::Statement(ref st) => st.is_negation_or_quantified(),
+            _ => false,
+        }
+    }
+
+    /// Returns an object that displays this formula as specified by `options`.
+    pub fn display_with(&self, options: DisplayOptions) -> impl fmt::Display + '_ {
+        WithOptions(self, options)
+    }
+}
+
+impl WriteWithOptions for Formula {
+    fn write(&self, f: &mut fmt::Formatter, options: &DisplayOptions) -> fmt::Result {
+        let symbols = options.notation.symbols();
+
+        match self {
+            Formula::Statement(ref st) => st.write(f, options),
+            Formula::Predicate(ref pred_letter, ref terms) => {
+                pred_letter.write(f, options)?;
+                terms.iter().try_for_each(|x| x.write(f, options))
+            }
+            Formula::Identity(ref lterm, ref rterm) => {
+                lterm.write(f, options)?;
+                write!(f, " {} ", symbols.identity)?;
+                rterm.write(f, options)
+            }
+            Formula::Conjunction(ref lformula, ref rformula) => {
+                write_binary(f, options, &**lformula, symbols.conjunction, &**rformula)
+            }
+            Formula::Negation(ref rformula) => {
+                write!(f, "{}", symbols.negation)?;
+                write_operand(
+                    f,
+                    options,
+                    &**rformula,
+                    rformula.is_negation_or_quantified(),
+                )
+            }
+            Formula::Disjunction(ref lformula, ref rformula) => {
+                write_binary(f, options, &**lformula, symbols.disjunction, &**rformula)
+            }
+            Formula::Conditional(ref lformula, ref rformula) => {
+                write_binary(f, options, &**lformula, symbols.conditional, &**rformula)
+            }
+            Formula::Biconditional(ref lformula, ref rformula) => {
+                write_binary(f, options, &**lformula, symbols.biconditional, &**rformula)
+            }
+        }
+    }
+}
+
+impl Term {
+    /// Returns an object that displays this term as specified by `options`.
+    pub fn display_with(&self, options: DisplayOptions) -> impl fmt::Display + '_ {
+        WithOptions(self, options)
+    }
+}
+
+impl fmt::Display for SimpleStatementLetter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, &DisplayOptions::default())
+    }
+}
+
+impl fmt::Display for SingularTerm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, &DisplayOptions::default())
+    }
+}
+
+impl fmt::Display for Variable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, &DisplayOptions::default())
+    }
+}
+
+impl fmt::Display for FunctionSymbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, &DisplayOptions::default())
+    }
+}
+
+impl fmt::Display for PredicateLetter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, &DisplayOptions::default())
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, &DisplayOptions::default())
+    }
+}
+
+impl fmt::Display for Statement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, &DisplayOptions::default())
+    }
+}
+
+impl fmt::Display for Formula {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.write(f, &DisplayOptions::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{Input, Parser};
+    use proptest::prelude::*;
+
+    fn full_ascii() -> DisplayOptions {
+        DisplayOptions {
+            notation: Notation::Ascii,
+            parenthesization: Parenthesization::Full,
+        }
+    }
+
+    #[test]
+    fn displays_in_book_notation() {
+        let parser = Parser::new();
+
+        let input = "∀x((A¹x & B¹x) ⊃ ∀y(~C¹y ⊃ ∃z(A²zy ∨ ~z = a₁)))";
+
+        match parser.parse(input).unwrap().0 {
+            Input::Statement(st) => assert_eq!(st.to_string(), input),
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn displays_with_options() {
+        let parser = Parser::new();
+
+        match parser.parse("~∀x~(F₂¹x ≡ ∃y~G¹²xyabcdefghij)").unwrap().0 {
+            Input::Statement(st) => assert_eq!(
+                st.display_with(full_ascii()).to_string(),
+                "~((Ax)(~(F_2^1x <-> (Ey)(~G^12xyabcdefghij))))"
+            ),
+            _ => assert!(false),
+        }
+    }
+
+    const SIMPLE_STATEMENT_LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    const SINGULAR_TERM_LETTERS: &str = "abcdefghijklmnopqrstuvw";
+    const VARIABLE_LETTERS: &str = "xyz";
+
+    fn arb_subscript() -> impl Strategy<Value = Subscript> {
+        prop_oneof![
+            Just(Subscript(None)),
+            (1..25u64).prop_map(|x| Subscript(Some(x)))
+        ]
+    }
+
+    fn arb_letter(letters: &'static str) -> impl Strategy<Value = char> {
+        prop::sample::select(letters.chars().collect::<Vec<_>>())
+    }
+
+    fn arb_singular_term() -> impl Strategy<Value = SingularTerm> {
+        (arb_letter(SINGULAR_TERM_LETTERS), arb_subscript()).prop_map(|(c, s)| SingularTerm(c, s))
+    }
+
+    fn arb_variable() -> impl Strategy<Value = Variable> {
+        (arb_letter(VARIABLE_LETTERS), arb_subscript()).prop_map(|(c, s)| Variable(c, s))
+    }
+
+    fn arb_atomic_statement() -> BoxedStrategy<Statement> {
+        prop_oneof![
+            (arb_letter(SIMPLE_STATEMENT_LETTERS), arb_subscript())
+                .prop_map(|(c, s)| Statement::Simple(SimpleStatementLetter(c, s))),
+            (
+                arb_letter(SIMPLE_STATEMENT_LETTERS),
+                arb_subscript(),
+                prop::collection::vec(arb_singular_term(), 1..4)
+            )
+                .prop_map(|(c, s, terms)| Statement::Singular(
+                    PredicateLetter(c, s, Degree(terms.len() as u64)),
+                    terms
+                )),
+            (arb_singular_term(), arb_singular_term()).prop_map(|(l, r)| Statement::Identity(l, r)),
+        ]
+        .boxed()
+    }
+
+    fn arb_quantified(stack: Vec<Variable>, depth: u32) -> BoxedStrategy<Statement> {
+        // A quantifier can't bind a variable that is already bound
+        let free_stack = stack.clone();
+
+        (
+            arb_variable().prop_filter("variable already bound", move |x| !free_stack.contains(x)),
+            any::<bool>(),
+        )
+            .prop_flat_map(move |(var, existential)| {
+                let mut stack = stack.clone();
+                stack.push(var.clone());
+
+                arb_formula(stack, depth).prop_map(move |formula| {
+                    if existential {
+                        Statement::Existential(var.clone(), Box::new(formula))
+                    } else {
+                        Statement::Universal(var.clone(), Box::new(formula))
+                    }
+                })
+            })
+            .boxed()
+    }
+
+    fn arb_statement(depth: u32) -> BoxedStrategy<Statement> {
+        // Generates statements in the shape the parser produces them
+        if depth == 0 {
+            return arb_atomic_statement();
+        }
+
+        let sub = || arb_statement(depth - 1);
+
+        prop_oneof![
+            arb_atomic_statement(),
+            sub().prop_map(|x| Statement::LogicalNegation(Box::new(x))),
+            (sub(), sub())
+                .prop_map(|(l, r)| Statement::LogicalConjunction(Box::new(l), Box::new(r))),
+            (sub(), sub())
+                .prop_map(|(l, r)| Statement::LogicalDisjunction(Box::new(l), Box::new(r))),
+            (sub(), sub())
+                .prop_map(|(l, r)| Statement::LogicalConditional(Box::new(l), Box::new(r))),
+            (sub(), sub())
+                .prop_map(|(l, r)| Statement::LogicalBiconditional(Box::new(l), Box::new(r))),
+            arb_quantified(vec![], depth - 1),
+        ]
+        .boxed()
+    }
+
+    fn arb_formula(stack: Vec<Variable>, depth: u32) -> BoxedStrategy<Formula> {
+        // Generates formulas in the shape the parser produces them, which means
+        // predicates and identities always contain at least one variable in scope,
+        // and compound formulas are never wrapped in Formula::Statement, unless they
+        // are quantified statements
+        let arb_term = {
+            let stack = stack.clone();
+
+            prop_oneof![
+                arb_singular_term().prop_map(Term::SingularTerm),
+                prop::sample::select(stack).prop_map(Term::Variable),
+            ]
+        };
+
+        let is_variable = |x: &Term| match x {
+            Term::Variable(_) => true,
+            _ => false,
+        };
+
+        let atomic = prop_oneof![
+            arb_atomic_statement().prop_map(|x| Formula::Statement(Box::new(x))),
+            (
+                arb_letter(SIMPLE_STATEMENT_LETTERS),
+                arb_subscript(),
+                prop::collection::vec(arb_term.clone(), 1..4)
+            )
+                .prop_filter("predicate without variables", move |(_, _, terms)| terms
+                    .iter()
+                    .any(is_variable))
+                .prop_map(|(c, s, terms)| Formula::Predicate(
+                    PredicateLetter(c, s, Degree(terms.len() as u64)),
+                    terms
+                )),
+            (arb_term.clone(), arb_term)
+                .prop_filter("identity without variables", move |(l, r)| is_variable(l)
+                    || is_variable(r))
+                .prop_map(|(l, r)| Formula::Identity(l, r)),
+        ];
+
+        if depth == 0 {
+            return atomic.boxed();
+        }
+
+        let sub = || arb_formula(stack.clone(), depth - 1);
+
+        prop_oneof![
+            atomic,
+            sub().prop_map(|x| Formula::Negation(Box::new(x))),
+            (sub(), sub()).prop_map(|(l, r)| Formula::Conjunction(Box::new(l), Box::new(r))),
+            (sub(), sub()).prop_map(|(l, r)| Formula::Disjunction(Box::new(l), Box::new(r))),
+            (sub(), sub()).prop_map(|(l, r)| Formula::Conditional(Box::new(l), Box::new(r))),
+            (sub(), sub()).prop_map(|(l, r)| Formula::Biconditional(Box::new(l), Box::new(r))),
+            arb_quantified(stack.clone(), depth - 1).prop_map(|x| Formula::Statement(Box::new(x))),
+        ]
+        .boxed()
+    }
+
+    #[test]
+    fn displays_in_latex() {
+        let parser = Parser::new();
+
+        let options = DisplayOptions {
+            notation: Notation::Latex,
+            parenthesization: Parenthesization::Minimal,
+        };
+
+        match parser
+            .parse("∀x((A¹x & B¹x) ⊃ ∃z(~A²zx ≡ ~z = a₁))")
+            .unwrap()
+            .0
+        {
+            Input::Statement(st) => assert_eq!(
+                st.display_with(options).to_string(),
+                "\\forall x((A^{1}x \\& B^{1}x) \\supset \\exists z({\\sim}A^{2}zx \\equiv {\\sim}z = a_{1}))"
+            ),
+            _ => assert!(false),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips(
+            st in arb_statement(4),
+            notation in prop_oneof![Just(Notation::Unicode), Just(Notation::Ascii)],
+            parenthesization in prop_oneof![
+                Just(Parenthesization::Minimal),
+                Just(Parenthesization::Full)
+            ],
+        ) {
+            let options = DisplayOptions { notation, parenthesization };
+            let displayed = st.display_with(options).to_string();
+
+            match Parser::new().parse(&displayed) {
+                Ok(parse_tree) => prop_assert_eq!(parse_tree.0, Input::Statement(st), "{}", displayed),
+                Err(e) => prop_assert!(false, "{}\n{}", displayed, e),
+            }
+        }
+    }
+}
@@ -1,60 +1,281 @@
+use super::ast::{Formula, PredicateLetter, Term, Variable};
 use super::parser::Rule;
 use pest::error::Error as PestError;
 use pest::error::ErrorVariant as PestErrorVariant;
-use pest::Span;
+use pest::{Position, Span};
 use std::fmt;
 use std::error::Error;
 
 /// An error that may occur during parsing of the input, be it a syntax error
 /// or a semantical error.
 /// 
-/// In addition to line and column, it provides a formatted message
-/// underlining exactly where the error occurred and which error
-/// it is.
+/// In addition to where the error occurred and which kind of error it is,
+/// it provides a formatted message (through its `Display` implementation)
+/// underlining exactly where the error occurred and which error it is.
 #[derive(Debug)]
 pub struct ParseError {
     /// (line, column)
     pub location: (usize, usize),
+    /// (line, column) of the end of the error. Syntax errors occur at a single
+    /// position, so for those this is the same as `location`.
+    pub end_location: (usize, usize),
+    /// (start, end) byte offsets into the input.
+    pub span: (usize, usize),
+    /// Which error occurred.
+    pub kind: ParseErrorKind,
+    variant: PestErrorVariant<Rule>,
     decorated_message: String,
 }
 
+/// The kind of a [ParseError](struct.ParseError.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// The input doesn't follow the syntax. `expected` contains what was expected at the
+    /// position of the error, and `unexpected` what was found there but wasn't allowed.
+    UnexpectedToken {
+        expected: Vec<ExpectedToken>,
+        unexpected: Vec<ExpectedToken>,
+    },
+    /// The degree of a predicate letter doesn't match the number of terms that follow it.
+    DegreeMismatch {
+        predicate_letter: PredicateLetter,
+        terms: usize,
+    },
+    /// A predicate or identity refers to a variable that isn't bound by any quantifier
+    /// it is in the scope of.
+    VariableOutOfScope(Variable),
+    /// A quantifier binds a variable that is already bound by another quantifier
+    /// it is in the scope of.
+    VariableAlreadyBound(Variable),
+}
+
+/// Something that was expected in the input (or found but not allowed) where a
+/// [ParseErrorKind::UnexpectedToken](enum.ParseErrorKind.html#variant.UnexpectedToken)
+/// error occurred.
+///
+/// Connectives, quantifiers, parentheses and separators are never reported, only the parts
+/// of the input they may be followed by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectedToken {
+    /// A statement set, an argument, an equivalence or a statement, as the whole input.
+    Input,
+    StatementSet,
+    Argument,
+    Equivalence,
+    Statement,
+    /// A formula, i.e. what follows a quantifier and its variable.
+    Formula,
+    SimpleStatementLetter,
+    PredicateLetter,
+    SingularTerm,
+    Variable,
+    Subscript,
+    /// The degree of a predicate letter (its superscript).
+    Degree,
+    EndOfInput,
+}
+
+impl ExpectedToken {
+    // Which part of the input some rule of the grammar is. Silent rules (connectives,
+    // parentheses, etc.) are never reported by pest, so they aren't any
+    fn from_rule(rule: &Rule) -> Option<ExpectedToken> {
+        Some(match rule {
+            Rule::input => ExpectedToken::Input,
+            Rule::statement_set => ExpectedToken::StatementSet,
+            Rule::argument => ExpectedToken::Argument,
+            Rule::equivalence => ExpectedToken::Equivalence,
+            Rule::lone_statement
+            | Rule::statement
+            | Rule::premise
+            | Rule::conclusion
+            | Rule::complex_statement
+            | Rule::simple_statement
+            | Rule::logical_conjunction
+            | Rule::logical_negation
+            | Rule::logical_disjunction
+            | Rule::logical_conditional
+            | Rule::logical_biconditional
+            | Rule::existential_statement
+            | Rule::universal_statement
+            | Rule::singular_statement
+            | Rule::identity_statement => ExpectedToken::Statement,
+            Rule::formula
+            | Rule::compound_formula
+            | Rule::atomic_formula
+            | Rule::simple_predicate
+            | Rule::identity_formula
+            | Rule::compound_formula_conjunction
+            | Rule::compound_formula_negation
+            | Rule::compound_formula_disjunction
+            | Rule::compound_formula_conditional
+            | Rule::compound_formula_biconditional => ExpectedToken::Formula,
+            Rule::simple_statement_letter_alpha | Rule::simple_statement_letter => {
+                ExpectedToken::SimpleStatementLetter
+            }
+            Rule::predicate_letter_alpha | Rule::predicate_letter => ExpectedToken::PredicateLetter,
+            Rule::singular_term_alpha | Rule::singular_term => ExpectedToken::SingularTerm,
+            Rule::variable_alpha | Rule::variable => ExpectedToken::Variable,
+            Rule::subscript_number => ExpectedToken::Subscript,
+            Rule::superscript_number => ExpectedToken::Degree,
+            Rule::EOI => ExpectedToken::EndOfInput,
+            Rule::grouper_opening
+            | Rule::grouper_closing
+            | Rule::statement_separator
+            | Rule::statement_set_opening
+            | Rule::statement_set_closing
+            | Rule::conclusion_indicator
+            | Rule::equivalence_indicator
+            | Rule::conjunction_connective
+            | Rule::negation_connective
+            | Rule::disjunction_connective
+            | Rule::conditional_connective
+            | Rule::biconditional_connective
+            | Rule::identity_connective
+            | Rule::existential_quantifier
+            | Rule::universal_quantifier
+            | Rule::WHITESPACE => return None,
+            // Only used to recover from errors, so never expected
+            Rule::recovery
+            | Rule::recovery_statement_set
+            | Rule::recovery_statements
+            | Rule::recovery_statement_set_part
+            | Rule::recovery_part
+            | Rule::recovery_separator
+            | Rule::recovery_token => return None,
+        })
+    }
+
+    // Several rules may be the same part of the input, which is then only listed once
+    fn from_rules(rules: &[Rule]) -> Vec<ExpectedToken> {
+        let mut tokens = vec![];
+
+        for token in rules.iter().filter_map(ExpectedToken::from_rule) {
+            if !tokens.contains(&token) {
+                tokens.push(token);
+            }
+        }
+
+        tokens
+    }
+}
+
+/// An error found when checking statements that weren't parsed from some input (e.g. ones
+/// built with the [builder](builder/index.html) module), as done by
+/// [StatementSet::new](struct.StatementSet.html#method.new) and the like.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// An error that the parser would also have reported for the statement, had it been
+    /// written as input, i.e. one of the kinds
+    /// [DegreeMismatch](enum.ParseErrorKind.html#variant.DegreeMismatch),
+    /// [VariableOutOfScope](enum.ParseErrorKind.html#variant.VariableOutOfScope) and
+    /// [VariableAlreadyBound](enum.ParseErrorKind.html#variant.VariableAlreadyBound).
+    Parse(ParseErrorKind),
+    /// The statement contains a function term (which the parser never produces;
+    /// see [Term](enum.Term.html)).
+    FunctionTermNotAllowed(Term),
+    /// A predicate letter has degree 0, which can't be written (a predicate letter is always
+    /// followed by at least one term).
+    ZeroDegree(PredicateLetter),
+    /// The letter of some simple statement letter, predicate letter, singular term, variable
+    /// or function symbol has subscript 0, which can't be written.
+    ZeroSubscript(char),
+    /// A predicate or identity formula doesn't contain any variable. The parser reads these
+    /// as statements instead, e.g. `Formula::Statement(Statement::Singular(..))` rather than
+    /// `Formula::Predicate(..)`.
+    FormulaWithoutVariable(Formula),
+    /// A statement set was created without any statements.
+    EmptyStatementSet,
+}
+
 impl Error for ParseError {}
 
 impl ParseError {
-    pub(in crate::parser) fn new_from_custom_error(span: Span, decorated_message: &str) -> Self {
-        let e: PestError<Rule> = PestError::new_from_span(
-            PestErrorVariant::CustomError {
-                message: decorated_message.to_owned(),
-            },
-            span.clone(),
-        )
-        .renamed_rules(ParseError::renamed_rules);
+    pub(in crate::parser) fn new_from_custom_error(
+        span: Span,
+        kind: ParseErrorKind,
+        decorated_message: &str,
+    ) -> Self {
+        let variant = PestErrorVariant::CustomError {
+            message: decorated_message.to_owned(),
+        };
+
+        let e: PestError<Rule> = PestError::new_from_span(variant.clone(), span.clone())
+            .renamed_rules(ParseError::renamed_rules);
 
         ParseError {
             location: span.start_pos().line_col(),
+            end_location: span.end_pos().line_col(),
+            span: (span.start(), span.end()),
+            kind,
+            variant,
             decorated_message: format!("{}", e),
         }
     }
 
     pub(in crate::parser) fn new_from_parsing_error(e: PestError<Rule>) -> ParseError {
-        use pest::error::LineColLocation;
+        use pest::error::{InputLocation, LineColLocation};
 
         let location = match e.line_col {
             LineColLocation::Pos((line, col)) => (line, col),
             _ => unreachable!(), // is this actually unreachable? it's not documented
         };
 
+        let pos = match e.location {
+            InputLocation::Pos(pos) => pos,
+            _ => unreachable!(), // same as above
+        };
+
+        let kind = match e.variant {
+            PestErrorVariant::ParsingError {
+                ref positives,
+                ref negatives,
+            } => ParseErrorKind::UnexpectedToken {
+                expected: ExpectedToken::from_rules(positives),
+                unexpected: ExpectedToken::from_rules(negatives),
+            },
+            PestErrorVariant::CustomError { .. } => unreachable!(), // the grammar has no custom errors
+        };
+
         ParseError {
             location,
+            end_location: location,
+            span: (pos, pos),
+            kind,
+            variant: e.variant.clone(),
             decorated_message: format!("{}", e.renamed_rules(ParseError::renamed_rules)),
         }
     }
 
+    // Moves an error that occurred in some part of `input` that starts at byte `offset`
+    // to where it is in `input`
+    pub(in crate::parser) fn relocated(self, input: &str, offset: usize) -> ParseError {
+        let (start, end) = (self.span.0 + offset, self.span.1 + offset);
+
+        match self.variant {
+            PestErrorVariant::ParsingError { .. } => ParseError::new_from_parsing_error(
+                PestError::new_from_pos(self.variant, Position::new(input, start).unwrap()),
+            ),
+            PestErrorVariant::CustomError { ref message } => ParseError::new_from_custom_error(
+                Span::new(input, start, end).unwrap(),
+                self.kind,
+                message,
+            ),
+        }
+    }
+
     fn renamed_rules(r: &Rule) -> String {
         (match r {
             Rule::input => "input",
             Rule::statement_set => "statement set",
             Rule::argument => "argument",
+            Rule::equivalence => "equivalence",
+            Rule::lone_statement => "statement",
+            Rule::recovery => "input",
+            Rule::recovery_statement_set => "statement set",
+            Rule::recovery_statements => "statements",
+            Rule::recovery_statement_set_part | Rule::recovery_part => "statement",
+            Rule::recovery_separator => "statement separator",
+            Rule::recovery_token => "token",
             Rule::statement => "statement",
             Rule::grouper_opening => "grouper opening",
             Rule::grouper_closing => "grouper closing",
@@ -62,6 +283,7 @@ impl ParseError {
             Rule::statement_set_opening => "statement set opening",
             Rule::statement_set_closing => "statement set closing",
             Rule::conclusion_indicator => "conclusion indicator",
+            Rule::equivalence_indicator => "equivalence indicator",
             Rule::premise => "premise",
             Rule::conclusion => "conclusion",
             Rule::formula => "formula",
@@ -73,21 +295,27 @@ impl ParseError {
             Rule::compound_formula_negation => "negation of a formula",
             Rule::compound_formula_disjunction => "disjunction of formulas",
             Rule::compound_formula_conditional => "conditional formula",
+            Rule::compound_formula_biconditional => "biconditional formula",
             Rule::complex_statement => "complex statement",
             Rule::conjunction_connective => "conjunction connective",
             Rule::negation_connective => "negation connective",
             Rule::disjunction_connective => "disjunction connective",
             Rule::conditional_connective => "conditional connective",
+            Rule::biconditional_connective => "biconditional connective",
             Rule::existential_statement => "existential statement",
             Rule::universal_statement => "universal statement",
             Rule::logical_conjunction => "logical conjunction",
             Rule::logical_negation => "logical negation",
             Rule::logical_disjunction => "logical disjunction",
             Rule::logical_conditional => "logical conditional",
+            Rule::logical_biconditional => "logical biconditional",
             Rule::subscript_number => "subscript",
             Rule::simple_statement_letter_alpha => "simple statement letter",
             Rule::simple_statement_letter => "simple statement letter",
             Rule::singular_statement => "singular statement",
+            Rule::identity_connective => "identity sign",
+            Rule::identity_statement => "identity statement",
+            Rule::identity_formula => "identity formula",
             Rule::singular_term_alpha => "singular term",
             Rule::singular_term => "singular term",
             Rule::variable_alpha => "variable",
@@ -109,3 +337,42 @@ impl fmt::Display for ParseError {
         write!(f, "{}", self.decorated_message)
     }
 }
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ValidationError::Parse(ParseErrorKind::UnexpectedToken { .. }) => {
+                write!(f, "unexpected token")
+            }
+            ValidationError::Parse(ParseErrorKind::DegreeMismatch {
+                predicate_letter,
+                terms,
+            }) => write!(
+                f,
+                "degree of predicate letter {} doesn't match number of terms ({})",
+                predicate_letter, terms
+            ),
+            ValidationError::Parse(ParseErrorKind::VariableOutOfScope(var)) => {
+                write!(f, "variable {} isn't in scope", var)
+            }
+            ValidationError::Parse(ParseErrorKind::VariableAlreadyBound(var)) => {
+                write!(f, "variable {} is already bound to another quantifier", var)
+            }
+            ValidationError::FunctionTermNotAllowed(term) => {
+                write!(f, "function term {} isn't allowed", term)
+            }
+            ValidationError::ZeroDegree(predicate_letter) => {
+                write!(f, "predicate letter {} has degree 0", predicate_letter)
+            }
+            ValidationError::ZeroSubscript(letter) => {
+                write!(f, "letter {} has subscript 0", letter)
+            }
+            ValidationError::FormulaWithoutVariable(formula) => {
+                write!(f, "formula {} doesn't contain any variable", formula)
+            }
+            ValidationError::EmptyStatementSet => write!(f, "statement set is empty"),
+        }
+    }
+}
+
+impl Error for ValidationError {}
@@ -1,12 +1,36 @@
 mod ast;
+mod display;
 mod error;
 mod parser;
+mod validate;
 
 pub use ast::{
-    Degree, Formula, Input, ParseTree, PredicateLetter, SimpleStatementLetter, SingularTerm,
-    Statement, Subscript, Term, Variable,
+    Degree, Formula, FunctionSymbol, Input, ParseTree, PredicateLetter, SimpleStatementLetter,
+    SingularTerm, SourceSpan, SpanTree, Statement, Subscript, Term, Variable,
 };
 
+pub use display::{DisplayOptions, Notation, Parenthesization};
+
 pub use parser::Parser;
 
-pub use error::ParseError;
+pub use error::{ExpectedToken, ParseError, ParseErrorKind, ValidationError};
+
+pub(crate) use validate::validate_statement;
+
+// Parses input that is known to be a single statement, for tests
+#[cfg(test)]
+pub(crate) fn parse_statement(input: &str) -> Statement {
+    match Parser::new().parse(input).unwrap().0 {
+        Input::Statement(statement) => statement,
+        _ => unreachable!(),
+    }
+}
+
+// Parses input that is known to be a statement set, for tests
+#[cfg(test)]
+pub(crate) fn parse_statements(input: &str) -> Vec<Statement> {
+    match Parser::new().parse(input).unwrap().0 {
+        Input::StatementSet(statements) => statements,
+        _ => unreachable!(),
+    }
+}
@@ -1,8 +1,8 @@
 use super::ast::{
     Degree, Formula, Input, ParseTree, PredicateLetter, SimpleStatementLetter, SingularTerm,
-    Statement, Subscript, Term, Variable,
+    SourceSpan, SpanTree, Statement, Subscript, Term, Variable,
 };
-use super::error::ParseError;
+use super::error::{ParseError, ParseErrorKind};
 use pest::iterators::{Pair, Pairs};
 
 mod pest_parser {
@@ -14,6 +14,9 @@ mod pest_parser {
 use pest_parser::GeneratedParser;
 pub use pest_parser::Rule;
 
+// Converting into the AST carries on after semantic errors, so it may fail with several
+type ParseResult<T> = Result<T, Vec<ParseError>>;
+
 pub struct Parser {}
 
 impl Parser {
@@ -25,72 +28,173 @@ impl Parser {
         use pest::Parser;
 
         match GeneratedParser::parse(Rule::input, input) {
-            Ok(p) => self.into_ast(p),
+            Ok(p) => self.into_ast(p).map_err(|mut e| e.remove(0)),
             Err(e) => Err(ParseError::new_from_parsing_error(e)),
         }
     }
 
-    fn into_ast(&self, mut pairs: Pairs<'_, Rule>) -> Result<ParseTree, ParseError> {
+    // Same as parse, except that it returns every error found, in order of where they are
+    // in the input, rather than only the first one
+    pub fn parse_with_diagnostics(&self, input: &str) -> ParseResult<ParseTree> {
+        use pest::Parser;
+
+        match GeneratedParser::parse(Rule::input, input) {
+            Ok(p) => self.into_ast(p),
+            Err(e) => Err(self.recover(input, ParseError::new_from_parsing_error(e))),
+        }
+    }
+
+    // If the input isn't syntactically valid, the parser stops at the first error. Statements
+    // are separated by statement separators or the conclusion indicator, so whatever is in
+    // between can be parsed on its own to find errors in the statements after the first error.
+    // The first error is only kept if it isn't in any statement that isn't syntactically valid
+    // (e.g. a missing statement set closing), since otherwise it is found again.
+    fn recover(&self, input: &str, error: ParseError) -> Vec<ParseError> {
+        use pest::Parser;
+
+        let mut errors = vec![];
+        let mut error_in_statement = false;
+
+        for (start, end) in statement_ranges(input) {
+            let statement = &input[start..end];
+
+            let statement_errors = match GeneratedParser::parse(Rule::lone_statement, statement) {
+                Ok(mut p) => {
+                    let pair = p.next().unwrap().into_inner().next().unwrap();
+
+                    match self.statement_into_ast(pair, &Vec::new()) {
+                        Ok(_) => continue,
+                        Err(e) => e,
+                    }
+                }
+                Err(e) => {
+                    if start <= error.span.0 && error.span.0 <= end {
+                        error_in_statement = true;
+                    }
+
+                    vec![ParseError::new_from_parsing_error(e)]
+                }
+            };
+
+            errors.extend(statement_errors.into_iter().map(|x| x.relocated(input, start)));
+        }
+
+        if !error_in_statement {
+            let index = errors
+                .iter()
+                .position(|x| x.span.0 > error.span.0)
+                .unwrap_or(errors.len());
+
+            errors.insert(index, error);
+        }
+
+        errors
+    }
+
+    fn into_ast(&self, mut pairs: Pairs<'_, Rule>) -> ParseResult<ParseTree> {
         let inner = pairs.next().unwrap().into_inner().next().unwrap();
         match inner.as_rule() {
             Rule::statement_set => self.statement_set_into_ast(inner),
 
             Rule::argument => self.argument_into_ast(inner),
 
-            Rule::statement => match self.statement_into_ast(inner, &Vec::new()) {
-                Ok(st) => Ok(ParseTree(Input::Statement(st))),
-                Err(e) => Err(e),
-            },
+            Rule::equivalence => self.equivalence_into_ast(inner),
+
+            Rule::statement => {
+                let span_tree = span_tree(inner.clone());
+
+                match self.statement_into_ast(inner, &Vec::new()) {
+                    Ok(st) => Ok(ParseTree(Input::Statement(st), vec![span_tree])),
+                    Err(e) => Err(e),
+                }
+            }
 
             _ => unreachable!(),
         }
     }
 
-    fn statement_set_into_ast(&self, pair: Pair<Rule>) -> Result<ParseTree, ParseError> {
+    fn statement_set_into_ast(&self, pair: Pair<Rule>) -> ParseResult<ParseTree> {
         assert!(pair.as_rule() == Rule::statement_set);
 
         let mut statements = Vec::new();
+        let mut span_trees = Vec::new();
+        let mut errors = Vec::new();
 
+        // Each statement is checked even if some before it aren't valid,
+        // so that every error is reported at once
         for st_pair in pair.into_inner() {
+            span_trees.push(span_tree(st_pair.clone()));
+
             match self.statement_into_ast(st_pair, &Vec::new()) {
                 Ok(st) => statements.push(st),
-                Err(e) => return Err(e),
+                Err(e) => errors.extend(e),
             }
         }
 
-        Ok(ParseTree(Input::StatementSet(statements)))
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(ParseTree(Input::StatementSet(statements), span_trees))
     }
 
-    fn argument_into_ast(&self, pair: Pair<Rule>) -> Result<ParseTree, ParseError> {
+    fn argument_into_ast(&self, pair: Pair<Rule>) -> ParseResult<ParseTree> {
         assert!(pair.as_rule() == Rule::argument);
 
         let mut statements = Vec::new();
+        let mut span_trees = Vec::new();
+        let mut errors = Vec::new();
 
+        // Same as with statement sets
         for st_pair in pair.into_inner() {
             match st_pair.as_rule() {
                 Rule::premise | Rule::conclusion => {
+                    span_trees.push(span_tree(st_pair.clone()));
+
                     match self.statement_into_ast(st_pair.into_inner().next().unwrap(), &Vec::new())
                     {
                         Ok(st) => statements.push(st),
-                        Err(e) => return Err(e),
+                        Err(e) => errors.extend(e),
                     }
                 }
                 _ => unreachable!(),
             }
         }
 
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         // The grammar guarantees us that the conclusion comes last.
         // That means that the conclusion will be at the back of the vector
         let conclusion = statements.pop().unwrap();
 
-        Ok(ParseTree(Input::Argument(statements, conclusion)))
+        Ok(ParseTree(Input::Argument(statements, conclusion), span_trees))
+    }
+
+    fn equivalence_into_ast(&self, pair: Pair<Rule>) -> ParseResult<ParseTree> {
+        assert!(pair.as_rule() == Rule::equivalence);
+
+        let mut pairs = pair.into_inner();
+        let lpair = pairs.next().unwrap();
+        let rpair = pairs.next().unwrap();
+
+        let span_trees = vec![span_tree(lpair.clone()), span_tree(rpair.clone())];
+
+        // Same as with statement sets
+        let (lstatement, rstatement) = both(
+            self.statement_into_ast(lpair, &Vec::new()),
+            self.statement_into_ast(rpair, &Vec::new()),
+        )?;
+
+        Ok(ParseTree(Input::Equivalence(lstatement, rstatement), span_trees))
     }
 
     fn statement_into_ast(
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Statement, ParseError> {
+    ) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::statement);
 
         let inner = pair.into_inner().next().unwrap();
@@ -109,7 +213,7 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Statement, ParseError> {
+    ) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::complex_statement);
 
         let inner = pair.into_inner().next().unwrap();
@@ -119,6 +223,7 @@ impl Parser {
             Rule::logical_negation => self.logical_negation_into_ast(inner, &stack),
             Rule::logical_disjunction => self.logical_disjunction_into_ast(inner, &stack),
             Rule::logical_conditional => self.logical_conditional_into_ast(inner, &stack),
+            Rule::logical_biconditional => self.logical_biconditional_into_ast(inner, &stack),
             Rule::existential_statement => self.existential_statement_into_ast(inner, &stack),
             Rule::universal_statement => self.universal_statement_into_ast(inner, &stack),
             _ => unreachable!(),
@@ -129,13 +234,15 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Statement, ParseError> {
+    ) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::logical_conjunction);
 
         let mut inner = pair.into_inner();
 
-        let lstatement = self.statement_into_ast(inner.next().unwrap(), &stack.clone())?;
-        let rstatement = self.statement_into_ast(inner.next().unwrap(), &stack.clone())?;
+        let (lstatement, rstatement) = both(
+            self.statement_into_ast(inner.next().unwrap(), &stack.clone()),
+            self.statement_into_ast(inner.next().unwrap(), &stack.clone()),
+        )?;
 
         Ok(Statement::LogicalConjunction(
             Box::new(lstatement),
@@ -147,7 +254,7 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Statement, ParseError> {
+    ) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::logical_negation);
 
         let mut inner = pair.into_inner();
@@ -161,13 +268,15 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Statement, ParseError> {
+    ) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::logical_disjunction);
 
         let mut inner = pair.into_inner();
 
-        let lstatement = self.statement_into_ast(inner.next().unwrap(), &stack.clone())?;
-        let rstatement = self.statement_into_ast(inner.next().unwrap(), &stack.clone())?;
+        let (lstatement, rstatement) = both(
+            self.statement_into_ast(inner.next().unwrap(), &stack.clone()),
+            self.statement_into_ast(inner.next().unwrap(), &stack.clone()),
+        )?;
 
         Ok(Statement::LogicalDisjunction(
             Box::new(lstatement),
@@ -179,13 +288,15 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Statement, ParseError> {
+    ) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::logical_conditional);
 
         let mut inner = pair.into_inner();
 
-        let lstatement = self.statement_into_ast(inner.next().unwrap(), &stack.clone())?;
-        let rstatement = self.statement_into_ast(inner.next().unwrap(), &stack.clone())?;
+        let (lstatement, rstatement) = both(
+            self.statement_into_ast(inner.next().unwrap(), &stack.clone()),
+            self.statement_into_ast(inner.next().unwrap(), &stack.clone()),
+        )?;
 
         Ok(Statement::LogicalConditional(
             Box::new(lstatement),
@@ -193,27 +304,55 @@ impl Parser {
         ))
     }
 
+    fn logical_biconditional_into_ast(
+        &self,
+        pair: Pair<Rule>,
+        stack: &Vec<Variable>,
+    ) -> ParseResult<Statement> {
+        assert!(pair.as_rule() == Rule::logical_biconditional);
+
+        let mut inner = pair.into_inner();
+
+        let (lstatement, rstatement) = both(
+            self.statement_into_ast(inner.next().unwrap(), &stack.clone()),
+            self.statement_into_ast(inner.next().unwrap(), &stack.clone()),
+        )?;
+
+        Ok(Statement::LogicalBiconditional(
+            Box::new(lstatement),
+            Box::new(rstatement),
+        ))
+    }
+
     fn existential_statement_into_ast(
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Statement, ParseError> {
+    ) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::existential_statement);
 
         let mut inner = pair.clone().into_inner();
 
         let variable = self.variable_into_ast(inner.next().unwrap());
 
-        if stack.iter().any(|x| x == &variable) {
-            return Err(ParseError::new_from_custom_error(
+        let not_bound = if stack.iter().any(|x| x == &variable) {
+            Err(vec![ParseError::new_from_custom_error(
                 pair.as_span(),
+                ParseErrorKind::VariableAlreadyBound(variable.clone()),
                 "variable is already bound to another quantifier",
-            ));
-        }
+            )])
+        } else {
+            Ok(())
+        };
 
+        // The formula is still checked if the variable is already bound, so that
+        // errors inside it are reported too
         let mut stack = stack.clone();
         stack.push(variable.clone());
-        let formula = self.formula_into_ast(inner.next().unwrap(), &stack)?;
+        let (_, formula) = both(
+            not_bound,
+            self.formula_into_ast(inner.next().unwrap(), &stack),
+        )?;
 
         Ok(Statement::Existential(variable, Box::new(formula)))
     }
@@ -222,33 +361,42 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Statement, ParseError> {
+    ) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::universal_statement);
 
         let mut inner = pair.clone().into_inner();
 
         let variable = self.variable_into_ast(inner.next().unwrap());
 
-        if stack.iter().any(|x| x == &variable) {
-            return Err(ParseError::new_from_custom_error(
+        let not_bound = if stack.iter().any(|x| x == &variable) {
+            Err(vec![ParseError::new_from_custom_error(
                 pair.as_span(),
+                ParseErrorKind::VariableAlreadyBound(variable.clone()),
                 "variable is already bound to another quantifier",
-            ));
-        }
+            )])
+        } else {
+            Ok(())
+        };
 
+        // The formula is still checked if the variable is already bound, so that
+        // errors inside it are reported too
         let mut stack = stack.clone();
         stack.push(variable.clone());
-        let formula = self.formula_into_ast(inner.next().unwrap(), &stack)?;
+        let (_, formula) = both(
+            not_bound,
+            self.formula_into_ast(inner.next().unwrap(), &stack),
+        )?;
 
         Ok(Statement::Universal(variable, Box::new(formula)))
     }
 
-    fn simple_statement_into_ast(&self, pair: Pair<Rule>) -> Result<Statement, ParseError> {
+    fn simple_statement_into_ast(&self, pair: Pair<Rule>) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::simple_statement);
 
         let inner = pair.into_inner().next().unwrap();
 
         match inner.as_rule() {
+            Rule::identity_statement => Ok(self.identity_statement_into_ast(inner)),
             Rule::singular_statement => self.singular_statement_into_ast(inner),
             Rule::simple_statement_letter => {
                 let mut inner_inner = inner.into_inner();
@@ -265,7 +413,7 @@ impl Parser {
         }
     }
 
-    fn singular_statement_into_ast(&self, pair: Pair<Rule>) -> Result<Statement, ParseError> {
+    fn singular_statement_into_ast(&self, pair: Pair<Rule>) -> ParseResult<Statement> {
         assert!(pair.as_rule() == Rule::singular_statement);
 
         let mut inner = pair.clone().into_inner();
@@ -280,15 +428,30 @@ impl Parser {
             .collect::<Vec<SingularTerm>>();
 
         if predicate_letter.2 != terms.len() as u64 {
-            return Err(ParseError::new_from_custom_error(
+            return Err(vec![ParseError::new_from_custom_error(
                 pair.as_span(),
+                ParseErrorKind::DegreeMismatch {
+                    predicate_letter,
+                    terms: terms.len(),
+                },
                 "degree doesn't match number of terms specified",
-            ));
+            )]);
         }
 
         Ok(Statement::Singular(predicate_letter, terms))
     }
 
+    fn identity_statement_into_ast(&self, pair: Pair<Rule>) -> Statement {
+        assert!(pair.as_rule() == Rule::identity_statement);
+
+        let mut inner = pair.into_inner();
+
+        let lterm = self.singular_term_into_ast(inner.next().unwrap());
+        let rterm = self.singular_term_into_ast(inner.next().unwrap());
+
+        Statement::Identity(lterm, rterm)
+    }
+
     fn singular_term_into_ast(&self, pair: Pair<Rule>) -> SingularTerm {
         assert!(pair.as_rule() == Rule::singular_term);
 
@@ -322,6 +485,7 @@ impl Parser {
                 .unwrap()
                 .as_str()
                 .chars()
+                .filter(|x| *x != '^')
                 .map(|x| match x {
                     '\u{2070}' => '0',
                     '\u{00B9}' => '1',
@@ -333,6 +497,7 @@ impl Parser {
                     '\u{2077}' => '7',
                     '\u{2078}' => '8',
                     '\u{2079}' => '9',
+                    x @ '0'..='9' => x,
                     _ => unreachable!(),
                 })
                 .collect::<String>()
@@ -353,6 +518,7 @@ impl Parser {
         Subscript(Some(
             pair.as_str()
                 .chars()
+                .filter(|x| *x != '_')
                 .map(|x| match x {
                     '\u{2080}' => '0',
                     '\u{2081}' => '1',
@@ -364,6 +530,7 @@ impl Parser {
                     '\u{2087}' => '7',
                     '\u{2088}' => '8',
                     '\u{2089}' => '9',
+                    x @ '0'..='9' => x,
                     _ => unreachable!(),
                 })
                 .collect::<String>()
@@ -372,7 +539,7 @@ impl Parser {
         ))
     }
 
-    fn formula_into_ast(&self, pair: Pair<Rule>, stack: &Vec<Variable>) -> Result<Formula, ParseError> {
+    fn formula_into_ast(&self, pair: Pair<Rule>, stack: &Vec<Variable>) -> ParseResult<Formula> {
         assert!(pair.as_rule() == Rule::formula);
 
         let inner = pair.into_inner().next().unwrap();
@@ -391,7 +558,7 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Formula, ParseError> {
+    ) -> ParseResult<Formula> {
         assert!(pair.as_rule() == Rule::compound_formula);
 
         let inner = pair.into_inner().next().unwrap();
@@ -412,6 +579,9 @@ impl Parser {
             Rule::compound_formula_conditional => {
                 self.compound_formula_conditional_into_ast(inner, &stack)
             }
+            Rule::compound_formula_biconditional => {
+                self.compound_formula_biconditional_into_ast(inner, &stack)
+            }
             _ => unreachable!(),
         }
     }
@@ -420,13 +590,15 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Formula, ParseError> {
+    ) -> ParseResult<Formula> {
         assert!(pair.as_rule() == Rule::compound_formula_conjunction);
 
         let mut inner = pair.into_inner();
 
-        let lformula = self.formula_into_ast(inner.next().unwrap(), &stack.clone())?;
-        let rformula = self.formula_into_ast(inner.next().unwrap(), &stack.clone())?;
+        let (lformula, rformula) = both(
+            self.formula_into_ast(inner.next().unwrap(), &stack.clone()),
+            self.formula_into_ast(inner.next().unwrap(), &stack.clone()),
+        )?;
 
         Ok(Formula::Conjunction(Box::new(lformula), Box::new(rformula)))
     }
@@ -435,7 +607,7 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Formula, ParseError> {
+    ) -> ParseResult<Formula> {
         assert!(pair.as_rule() == Rule::compound_formula_negation);
 
         let mut inner = pair.into_inner();
@@ -449,13 +621,15 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Formula, ParseError> {
+    ) -> ParseResult<Formula> {
         assert!(pair.as_rule() == Rule::compound_formula_disjunction);
 
         let mut inner = pair.into_inner();
 
-        let lformula = self.formula_into_ast(inner.next().unwrap(), &stack.clone())?;
-        let rformula = self.formula_into_ast(inner.next().unwrap(), &stack.clone())?;
+        let (lformula, rformula) = both(
+            self.formula_into_ast(inner.next().unwrap(), &stack.clone()),
+            self.formula_into_ast(inner.next().unwrap(), &stack.clone()),
+        )?;
 
         Ok(Formula::Disjunction(Box::new(lformula), Box::new(rformula)))
     }
@@ -464,22 +638,44 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Formula, ParseError> {
+    ) -> ParseResult<Formula> {
         assert!(pair.as_rule() == Rule::compound_formula_conditional);
 
         let mut inner = pair.into_inner();
 
-        let lformula = self.formula_into_ast(inner.next().unwrap(), &stack.clone())?;
-        let rformula = self.formula_into_ast(inner.next().unwrap(), &stack.clone())?;
+        let (lformula, rformula) = both(
+            self.formula_into_ast(inner.next().unwrap(), &stack.clone()),
+            self.formula_into_ast(inner.next().unwrap(), &stack.clone()),
+        )?;
 
         Ok(Formula::Conditional(Box::new(lformula), Box::new(rformula)))
     }
 
+    fn compound_formula_biconditional_into_ast(
+        &self,
+        pair: Pair<Rule>,
+        stack: &Vec<Variable>,
+    ) -> ParseResult<Formula> {
+        assert!(pair.as_rule() == Rule::compound_formula_biconditional);
+
+        let mut inner = pair.into_inner();
+
+        let (lformula, rformula) = both(
+            self.formula_into_ast(inner.next().unwrap(), &stack.clone()),
+            self.formula_into_ast(inner.next().unwrap(), &stack.clone()),
+        )?;
+
+        Ok(Formula::Biconditional(
+            Box::new(lformula),
+            Box::new(rformula),
+        ))
+    }
+
     fn atomic_formula_into_ast(
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<Formula, ParseError> {
+    ) -> ParseResult<Formula> {
         assert!(pair.as_rule() == Rule::atomic_formula);
 
         let inner = pair.into_inner().next().unwrap();
@@ -491,6 +687,9 @@ impl Parser {
             Rule::simple_predicate => self
                 .simple_predicate_into_ast(inner, &stack)
                 .map(|(pred_letter, terms)| Formula::Predicate(pred_letter, terms)),
+            Rule::identity_formula => self
+                .identity_formula_into_ast(inner, &stack)
+                .map(|(lterm, rterm)| Formula::Identity(lterm, rterm)),
             _ => unreachable!(),
         }
     }
@@ -499,7 +698,7 @@ impl Parser {
         &self,
         pair: Pair<Rule>,
         stack: &Vec<Variable>,
-    ) -> Result<(PredicateLetter, Vec<Term>), ParseError> {
+    ) -> ParseResult<(PredicateLetter, Vec<Term>)> {
         assert!(pair.as_rule() == Rule::simple_predicate);
 
         let mut inner = pair.clone().into_inner();
@@ -514,26 +713,87 @@ impl Parser {
             })
             .collect::<Vec<Term>>();
 
+        let mut errors = vec![];
+
         if predicate_letter.2 != terms.len() as u64 {
-            return Err(ParseError::new_from_custom_error(
+            errors.push(ParseError::new_from_custom_error(
                 pair.as_span(),
+                ParseErrorKind::DegreeMismatch {
+                    predicate_letter: predicate_letter.clone(),
+                    terms: terms.len(),
+                },
                 "degree doesn't match number of terms specified",
             ));
         }
 
-        if !terms.iter().all(|x| match x {
-            Term::Variable(var) => stack.contains(var),
-            _ => true,
-        }) {
-            return Err(ParseError::new_from_custom_error(
+        let mut out_of_scope = vec![];
+
+        for term in &terms {
+            if let Term::Variable(var) = term {
+                if !stack.contains(var) && !out_of_scope.contains(&var) {
+                    out_of_scope.push(var);
+                }
+            }
+        }
+
+        for var in out_of_scope {
+            errors.push(ParseError::new_from_custom_error(
                 pair.as_span(),
+                ParseErrorKind::VariableOutOfScope(var.clone()),
                 "predicate binds to variable that isn't in scope",
             ));
         }
 
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
         Ok((predicate_letter, terms))
     }
 
+    fn identity_formula_into_ast(
+        &self,
+        pair: Pair<Rule>,
+        stack: &Vec<Variable>,
+    ) -> ParseResult<(Term, Term)> {
+        assert!(pair.as_rule() == Rule::identity_formula);
+
+        let mut terms = pair.clone().into_inner().map(|x| match x.as_rule() {
+            Rule::singular_term => Term::SingularTerm(self.singular_term_into_ast(x)),
+            Rule::variable => Term::Variable(self.variable_into_ast(x)),
+            _ => unreachable!(),
+        });
+
+        let lterm = terms.next().unwrap();
+        let rterm = terms.next().unwrap();
+
+        let mut out_of_scope = vec![];
+
+        for term in &[&lterm, &rterm] {
+            if let Term::Variable(var) = term {
+                if !stack.contains(var) && !out_of_scope.contains(&var) {
+                    out_of_scope.push(var);
+                }
+            }
+        }
+
+        let mut errors = vec![];
+
+        for var in out_of_scope {
+            errors.push(ParseError::new_from_custom_error(
+                pair.as_span(),
+                ParseErrorKind::VariableOutOfScope(var.clone()),
+                "identity binds to variable that isn't in scope",
+            ));
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok((lterm, rterm))
+    }
+
     fn variable_into_ast(&self, pair: Pair<Rule>) -> Variable {
         assert!(pair.as_rule() == Rule::variable);
 
@@ -550,16 +810,93 @@ impl Parser {
     }
 }
 
+// Byte ranges of what may be the statements of some input, if it is a statement set,
+// an argument or an equivalence
+fn statement_ranges(input: &str) -> Vec<(usize, usize)> {
+    use pest::Parser;
+
+    // The recovery rule matches any input
+    let pair = GeneratedParser::parse(Rule::recovery, input)
+        .unwrap()
+        .next()
+        .unwrap();
+
+    pair.into_inner()
+        .next()
+        .unwrap()
+        .into_inner()
+        .map(|x| (x.as_span().start(), x.as_span().end()))
+        .collect()
+}
+
+// Where the statement, formula, or term of `pair` and each of its parts are in the input,
+// mirroring the AST that it's converted into
+fn span_tree(pair: Pair<Rule>) -> SpanTree {
+    match pair.as_rule() {
+        // These only contain the rule of what is converted into the AST (or grouping parentheses
+        // around it)
+        Rule::premise
+        | Rule::conclusion
+        | Rule::statement
+        | Rule::complex_statement
+        | Rule::simple_statement
+        | Rule::formula
+        | Rule::compound_formula
+        | Rule::atomic_formula => span_tree(pair.into_inner().next().unwrap()),
+        // Identifiers, which are made of rules that aren't part of the AST
+        Rule::simple_statement_letter
+        | Rule::predicate_letter
+        | Rule::singular_term
+        | Rule::variable => SpanTree {
+            span: source_span(&pair),
+            children: vec![],
+        },
+        _ => SpanTree {
+            span: source_span(&pair),
+            children: pair.into_inner().map(span_tree).collect(),
+        },
+    }
+}
+
+fn source_span(pair: &Pair<Rule>) -> SourceSpan {
+    let span = pair.as_span();
+
+    SourceSpan {
+        start: span.start(),
+        end: span.end(),
+        start_location: span.start_pos().line_col(),
+        end_location: span.end_pos().line_col(),
+    }
+}
+
+// Combines the results of checking two parts of the input, keeping the errors of both
+fn both<A, B>(a: ParseResult<A>, b: ParseResult<B>) -> ParseResult<(A, B)> {
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok((a, b)),
+        (a, b) => {
+            let mut errors = a.err().unwrap_or_default();
+            errors.extend(b.err().unwrap_or_default());
+            Err(errors)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::parser::ExpectedToken;
     use pest::Span;
 
     #[test]
     fn custom_error_provides_correct_location_info() {
-        let e =
-            ParseError::new_from_custom_error(Span::new("Hello world!", 0, 4).unwrap(), "missing comma");
+        let e = ParseError::new_from_custom_error(
+            Span::new("Hello world!", 0, 4).unwrap(),
+            ParseErrorKind::VariableAlreadyBound(Variable('x', Subscript(None))),
+            "missing comma",
+        );
         assert!(e.location.0 == 1 && e.location.1 == 1);
+        assert!(e.end_location.0 == 1 && e.end_location.1 == 5);
+        assert_eq!(e.span, (0, 4));
     }
 
     #[test]
@@ -588,6 +925,19 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_equivalence() {
+        let parser = Parser::new();
+
+        match parser.parse("(A ⊃ B) ⟚ (~A ∨ B)") {
+            Ok(ref parse_tree) => match parse_tree.0 {
+                Input::Equivalence(_, _) => assert_eq!(parse_tree.1.len(), 2),
+                _ => assert!(false),
+            },
+            Err(e) => assert!(false, format!("{}", e)),
+        }
+    }
+
     #[test]
     fn parses_single_statement() {
         let parser = Parser::new();
@@ -689,6 +1039,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_logical_biconditional() {
+        let parser = Parser::new();
+
+        match parser.parse("{(A ≡ B)}") {
+            Ok(parse_tree) => match parse_tree.0 {
+                Input::StatementSet(mut statements) => {
+                    assert!(statements.len() == 1);
+                    match statements.pop().unwrap() {
+                        Statement::LogicalBiconditional(a, b) => match (*a, *b) {
+                            (Statement::Simple(_), Statement::Simple(_)) => {}
+                            _ => assert!(false),
+                        },
+                        _ => assert!(false),
+                    }
+                }
+                _ => assert!(false),
+            },
+            Err(e) => assert!(false, format!("{}", e)),
+        }
+    }
+
+    #[test]
+    fn parses_biconditional_formula() {
+        let parser = Parser::new();
+
+        match parser.parse("{∀z(A¹z ≡ B¹z)}") {
+            Ok(parse_tree) => match parse_tree.0 {
+                Input::StatementSet(mut statements) => {
+                    assert!(statements.len() == 1);
+                    match statements.pop().unwrap() {
+                        Statement::Universal(a, b) => match (a, *b) {
+                            (Variable(_, _), Formula::Biconditional(_, _)) => {}
+                            _ => assert!(false),
+                        },
+                        _ => assert!(false),
+                    }
+                }
+                _ => assert!(false),
+            },
+            Err(e) => assert!(false, format!("{}", e)),
+        }
+    }
+
     #[test]
     fn parses_existential_statement() {
         let parser = Parser::new();
@@ -800,6 +1194,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parses_identity_statement() {
+        let parser = Parser::new();
+
+        match parser.parse("{~a₁ = b}") {
+            Ok(parse_tree) => match parse_tree.0 {
+                Input::StatementSet(mut statements) => {
+                    assert!(statements.len() == 1);
+                    match statements.pop().unwrap() {
+                        Statement::LogicalNegation(a) => match *a {
+                            Statement::Identity(lterm, rterm) => {
+                                assert!(lterm == SingularTerm('a', Subscript(Some(1))));
+                                assert!(rterm == SingularTerm('b', Subscript(None)));
+                            }
+                            _ => assert!(false),
+                        },
+                        _ => assert!(false),
+                    }
+                }
+                _ => assert!(false),
+            },
+            Err(e) => assert!(false, format!("{}", e)),
+        }
+    }
+
+    #[test]
+    fn parses_identity_formula() {
+        let parser = Parser::new();
+
+        match parser.parse("{∀x∃y(x = y & ~x = a)}") {
+            Ok(parse_tree) => match parse_tree.0 {
+                Input::StatementSet(mut statements) => {
+                    assert!(statements.len() == 1);
+                    match statements.pop().unwrap() {
+                        Statement::Universal(_, formula) => match *formula {
+                            Formula::Statement(st) => match *st {
+                                Statement::Existential(_, formula) => match *formula {
+                                    Formula::Conjunction(a, b) => match (*a, *b) {
+                                        (Formula::Identity(_, _), Formula::Negation(_)) => {}
+                                        _ => assert!(false),
+                                    },
+                                    _ => assert!(false),
+                                },
+                                _ => assert!(false),
+                            },
+                            _ => assert!(false),
+                        },
+                        _ => assert!(false),
+                    }
+                }
+                _ => assert!(false),
+            },
+            Err(e) => assert!(false, format!("{}", e)),
+        }
+    }
+
+    #[test]
+    fn identity_formula_keeps_track_of_variable_stack() {
+        let parser = Parser::new();
+
+        match parser.parse("{∃x(x = y)}") {
+            Ok(_) => assert!(false),
+            _ => {}
+        }
+    }
+
     #[test]
     fn singular_statement_doesnt_allow_variables() {
         let parser = Parser::new();
@@ -849,4 +1309,293 @@ mod tests {
             Err(_) => assert!(false),
         }
     }
+
+    #[test]
+    fn ascii_dialect_produces_same_parse_tree() {
+        let parser = Parser::new();
+
+        let pairs = [
+            (
+                "∀x((A¹x & B¹x) ⊃ ∀y(~C¹y ⊃ ∃z(A²zy ∨ B¹z)))",
+                "Ax((A^1x & B^1x) -> Ay(~C^1y -> Ez(A^2zy v B^1z)))",
+            ),
+            (
+                "(∀x)(F¹x ≡ (∃y)G²xy)",
+                "(forall x)(F^1x <-> (exists y)G^2xy)",
+            ),
+            ("(A₁ ∨ B₁₀)", "(A_1 | B_10)"),
+            ("{F¹a₂, ~a₂ = b}", "{F^1a_2, ~a_2 = b}"),
+            ("A, (A ⊃ B) ∴ B", "A, (A -> B) therefore B"),
+            ("A, (A ⊃ B) ∴ B", "A, (A -> B) :. B"),
+            ("(A ⊃ B) ⟚ (~A ∨ B)", "(A -> B) <=> (~A v B)"),
+            ("P²ab", "P^2ab"),
+            ("P¹⁰abcdefghij", "P^10abcdefghij"),
+        ];
+
+        for (unicode, ascii) in pairs.iter() {
+            // Spans differ, since symbols differ in length
+            assert_eq!(parser.parse(unicode).unwrap().0, parser.parse(ascii).unwrap().0);
+        }
+    }
+
+    #[test]
+    fn ascii_subscripts_and_superscripts_cannot_start_with_zero() {
+        let parser = Parser::new();
+
+        assert!(parser.parse("A_0").is_err());
+        assert!(parser.parse("F^01a").is_err());
+    }
+
+    #[test]
+    fn parse_tree_has_span_trees() {
+        let parser = Parser::new();
+
+        let span = |start, end| SourceSpan {
+            start,
+            end,
+            start_location: (1, start + 1),
+            end_location: (1, end + 1),
+        };
+
+        match parser.parse("{(A & B), ~(C)}") {
+            Ok(parse_tree) => assert_eq!(
+                parse_tree.1,
+                vec![
+                    SpanTree {
+                        span: span(1, 8),
+                        children: vec![
+                            SpanTree {
+                                span: span(2, 3),
+                                children: vec![]
+                            },
+                            SpanTree {
+                                span: span(6, 7),
+                                children: vec![]
+                            },
+                        ]
+                    },
+                    SpanTree {
+                        span: span(10, 14),
+                        children: vec![SpanTree {
+                            span: span(12, 13),
+                            children: vec![]
+                        }]
+                    },
+                ]
+            ),
+            Err(e) => assert!(false, format!("{}", e)),
+        }
+
+        // Spans are counted in bytes, locations in characters
+        match parser.parse("∃xF¹x") {
+            Ok(parse_tree) => {
+                let span_tree = &parse_tree.1[0];
+
+                assert_eq!((span_tree.span.start, span_tree.span.end), (0, 8));
+                assert_eq!(span_tree.span.end_location, (1, 6));
+                assert_eq!(span_tree.children.len(), 2);
+                assert_eq!(span_tree.children[1].children.len(), 2);
+            }
+            Err(e) => assert!(false, format!("{}", e)),
+        }
+    }
+
+    #[test]
+    fn spans_do_not_affect_equality_of_statements() {
+        let parser = Parser::new();
+
+        let lhs = parser.parse("{(A & B), A}").unwrap();
+        let rhs = parser.parse("{A, ((A & B))}").unwrap();
+
+        match (lhs.0, rhs.0) {
+            (Input::StatementSet(lhs), Input::StatementSet(rhs)) => {
+                assert_eq!(lhs[0], rhs[1]);
+                assert_eq!(lhs[1], rhs[0]);
+            }
+            _ => assert!(false),
+        }
+
+        assert_ne!(lhs.1[0], rhs.1[1]);
+    }
+
+    #[test]
+    fn reports_all_semantic_errors() {
+        let parser = Parser::new();
+
+        match parser.parse_with_diagnostics("{∃x(A²xy & ∃xB¹z), C¹ab}") {
+            Ok(_) => assert!(false),
+            Err(errors) => assert_eq!(
+                errors.into_iter().map(|x| x.kind).collect::<Vec<_>>(),
+                vec![
+                    ParseErrorKind::VariableOutOfScope(Variable('y', Subscript(None))),
+                    ParseErrorKind::VariableAlreadyBound(Variable('x', Subscript(None))),
+                    ParseErrorKind::VariableOutOfScope(Variable('z', Subscript(None))),
+                    ParseErrorKind::DegreeMismatch {
+                        predicate_letter: PredicateLetter('C', Subscript(None), Degree(1)),
+                        terms: 2
+                    },
+                ]
+            ),
+        }
+
+        // parse only returns the first one
+        match parser.parse("{∃x(A²xy & ∃xB¹z), C¹ab}") {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(
+                e.kind,
+                ParseErrorKind::VariableOutOfScope(Variable('y', Subscript(None)))
+            ),
+        }
+    }
+
+    #[test]
+    fn checks_predicate_after_degree_mismatch() {
+        let parser = Parser::new();
+
+        match parser.parse_with_diagnostics("∃xA¹xyz") {
+            Ok(_) => assert!(false),
+            Err(errors) => assert_eq!(
+                errors.into_iter().map(|x| x.kind).collect::<Vec<_>>(),
+                vec![
+                    ParseErrorKind::DegreeMismatch {
+                        predicate_letter: PredicateLetter('A', Subscript(None), Degree(1)),
+                        terms: 3
+                    },
+                    ParseErrorKind::VariableOutOfScope(Variable('y', Subscript(None))),
+                    ParseErrorKind::VariableOutOfScope(Variable('z', Subscript(None))),
+                ]
+            ),
+        }
+    }
+
+    #[test]
+    fn checks_both_terms_of_identity() {
+        let parser = Parser::new();
+
+        match parser.parse_with_diagnostics("{∃x(y = z), ∃x(y = y)}") {
+            Ok(_) => assert!(false),
+            Err(errors) => assert_eq!(
+                errors.into_iter().map(|x| x.kind).collect::<Vec<_>>(),
+                vec![
+                    ParseErrorKind::VariableOutOfScope(Variable('y', Subscript(None))),
+                    ParseErrorKind::VariableOutOfScope(Variable('z', Subscript(None))),
+                    ParseErrorKind::VariableOutOfScope(Variable('y', Subscript(None))),
+                ]
+            ),
+        }
+    }
+
+    #[test]
+    fn statement_ranges() {
+        assert_eq!(super::statement_ranges("{A, B}"), vec![(1, 2), (3, 5)]);
+        assert_eq!(super::statement_ranges(" {A, B"), vec![(2, 3), (4, 6)]);
+        assert_eq!(
+            super::statement_ranges("A, B ∴ C"),
+            vec![(0, 1), (2, 5), (8, 10)]
+        );
+        assert_eq!(super::statement_ranges("A"), vec![(0, 1)]);
+        assert_eq!(super::statement_ranges("{A, B} C"), vec![(1, 2), (3, 5)]);
+
+        // "therefore" isn't a separator where it is a run of singular terms
+        assert_eq!(
+            super::statement_ranges("A therefore B"),
+            vec![(0, 2), (11, 13)]
+        );
+        assert_eq!(
+            super::statement_ranges("A, F⁹therefore ∴ B"),
+            vec![(0, 1), (2, 17), (20, 22)]
+        );
+        assert_eq!(
+            super::statement_ranges("A, F^10thereforea_1 :. B"),
+            vec![(0, 1), (2, 20), (22, 24)]
+        );
+    }
+
+    #[test]
+    fn syntax_error_is_unexpected_token() {
+        let parser = Parser::new();
+
+        match parser.parse("{A, B") {
+            Ok(_) => assert!(false),
+            Err(e) => {
+                match e.kind {
+                    ParseErrorKind::UnexpectedToken { ref expected, .. } => {
+                        assert!(expected.contains(&ExpectedToken::Subscript))
+                    }
+                    _ => assert!(false),
+                }
+
+                assert_eq!(e.location, (1, 6));
+                assert_eq!(e.end_location, (1, 6));
+                assert_eq!(e.span, (5, 5));
+            }
+        }
+    }
+
+    #[test]
+    fn syntax_error_lists_expected_tokens() {
+        let parser = Parser::new();
+
+        // A variable may be followed by its subscript or by the formula of its quantifier
+        match parser.parse("∀x") {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(
+                e.kind,
+                ParseErrorKind::UnexpectedToken {
+                    expected: vec![ExpectedToken::Formula, ExpectedToken::Subscript],
+                    unexpected: vec![]
+                }
+            ),
+        }
+    }
+
+    #[test]
+    fn degree_mismatch_error_has_kind_and_span() {
+        let parser = Parser::new();
+
+        match parser.parse("{∃zA¹zs}") {
+            Ok(_) => assert!(false),
+            Err(e) => {
+                assert_eq!(
+                    e.kind,
+                    ParseErrorKind::DegreeMismatch {
+                        predicate_letter: PredicateLetter('A', Subscript(None), Degree(1)),
+                        terms: 2
+                    }
+                );
+                assert_eq!(e.location, (1, 4));
+                assert_eq!(e.end_location, (1, 8));
+                assert_eq!(e.span, (5, 10));
+            }
+        }
+    }
+
+    #[test]
+    fn variable_errors_have_kinds() {
+        let parser = Parser::new();
+
+        match parser.parse("{∃zA¹y}") {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(
+                e.kind,
+                ParseErrorKind::VariableOutOfScope(Variable('y', Subscript(None)))
+            ),
+        }
+
+        match parser.parse("{∃x(x = y)}") {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(
+                e.kind,
+                ParseErrorKind::VariableOutOfScope(Variable('y', Subscript(None)))
+            ),
+        }
+
+        match parser.parse("{∃x∀xA¹x}") {
+            Ok(_) => assert!(false),
+            Err(e) => assert_eq!(
+                e.kind,
+                ParseErrorKind::VariableAlreadyBound(Variable('x', Subscript(None)))
+            ),
+        }
+    }
 }
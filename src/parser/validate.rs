@@ -0,0 +1,344 @@
+use super::ast::{Formula, PredicateLetter, Statement, Subscript, Term, Variable};
+use super::error::{ParseErrorKind, ValidationError};
+
+// Checks that a statement that wasn't parsed (e.g. built by hand) is semantically valid,
+// the same way the parser does after parsing: the degree of each predicate letter must match
+// the number of terms, every variable must be in scope, and no quantifier may bind a variable
+// that is already bound. Also rejects what can't be written at all, i.e. function terms,
+// degree or subscript 0, and predicates or identities without variables in formulas (which
+// the parser reads as statements). Returns the first error found.
+pub fn validate_statement(statement: &Statement) -> Result<(), ValidationError> {
+    validate_statement_in_scope(statement, &[])
+}
+
+fn validate_statement_in_scope(
+    statement: &Statement,
+    stack: &[Variable],
+) -> Result<(), ValidationError> {
+    match statement {
+        Statement::Simple(simple_statement_letter) => {
+            validate_subscript(simple_statement_letter.0, &simple_statement_letter.1)
+        }
+        Statement::Identity(lterm, rterm) => {
+            validate_subscript(lterm.0, &lterm.1)?;
+            validate_subscript(rterm.0, &rterm.1)
+        }
+        Statement::Singular(predicate_letter, terms) => {
+            validate_predicate_letter(predicate_letter, terms.len())?;
+
+            for term in terms {
+                validate_subscript(term.0, &term.1)?;
+            }
+
+            Ok(())
+        }
+        Statement::LogicalConjunction(lstatement, rstatement)
+        | Statement::LogicalDisjunction(lstatement, rstatement)
+        | Statement::LogicalConditional(lstatement, rstatement)
+        | Statement::LogicalBiconditional(lstatement, rstatement) => {
+            validate_statement_in_scope(lstatement, stack)?;
+            validate_statement_in_scope(rstatement, stack)
+        }
+        Statement::LogicalNegation(rstatement) => validate_statement_in_scope(rstatement, stack),
+        Statement::Existential(variable, formula) | Statement::Universal(variable, formula) => {
+            validate_subscript(variable.0, &variable.1)?;
+
+            if stack.contains(variable) {
+                return Err(ValidationError::Parse(
+                    ParseErrorKind::VariableAlreadyBound(variable.clone()),
+                ));
+            }
+
+            let mut stack = stack.to_vec();
+            stack.push(variable.clone());
+            validate_formula(formula, &stack)
+        }
+    }
+}
+
+fn validate_formula(formula: &Formula, stack: &[Variable]) -> Result<(), ValidationError> {
+    match formula {
+        Formula::Statement(statement) => validate_statement_in_scope(statement, stack),
+        Formula::Predicate(predicate_letter, terms) => {
+            validate_predicate_letter(predicate_letter, terms.len())?;
+            validate_terms(terms.iter(), stack)?;
+            validate_has_variable(formula, terms.iter())
+        }
+        Formula::Identity(lterm, rterm) => {
+            validate_terms([lterm, rterm].iter().cloned(), stack)?;
+            validate_has_variable(formula, [lterm, rterm].iter().cloned())
+        }
+        Formula::Conjunction(lformula, rformula)
+        | Formula::Disjunction(lformula, rformula)
+        | Formula::Conditional(lformula, rformula)
+        | Formula::Biconditional(lformula, rformula) => {
+            validate_formula(lformula, stack)?;
+            validate_formula(rformula, stack)
+        }
+        Formula::Negation(rformula) => validate_formula(rformula, stack),
+    }
+}
+
+fn validate_predicate_letter(
+    predicate_letter: &PredicateLetter,
+    terms: usize,
+) -> Result<(), ValidationError> {
+    validate_subscript(predicate_letter.0, &predicate_letter.1)?;
+
+    if (predicate_letter.2).0 == 0 {
+        return Err(ValidationError::ZeroDegree(predicate_letter.clone()));
+    }
+
+    if (predicate_letter.2).0 != terms as u64 {
+        return Err(ValidationError::Parse(ParseErrorKind::DegreeMismatch {
+            predicate_letter: predicate_letter.clone(),
+            terms,
+        }));
+    }
+
+    Ok(())
+}
+
+fn validate_terms<'a, I>(terms: I, stack: &[Variable]) -> Result<(), ValidationError>
+where
+    I: Iterator<Item = &'a Term>,
+{
+    for term in terms {
+        match term {
+            Term::Variable(var) => {
+                validate_subscript(var.0, &var.1)?;
+
+                if !stack.contains(var) {
+                    return Err(ValidationError::Parse(ParseErrorKind::VariableOutOfScope(
+                        var.clone(),
+                    )));
+                }
+            }
+            Term::Function(_, _) => {
+                return Err(ValidationError::FunctionTermNotAllowed(term.clone()));
+            }
+            Term::SingularTerm(singular_term) => {
+                validate_subscript(singular_term.0, &singular_term.1)?
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// A predicate or identity without variables is parsed as a statement, never as a formula
+fn validate_has_variable<'a, I>(formula: &Formula, terms: I) -> Result<(), ValidationError>
+where
+    I: Iterator<Item = &'a Term>,
+{
+    for term in terms {
+        if let Term::Variable(_) = term {
+            return Ok(());
+        }
+    }
+
+    Err(ValidationError::FormulaWithoutVariable(formula.clone()))
+}
+
+fn validate_subscript(letter: char, subscript: &Subscript) -> Result<(), ValidationError> {
+    if subscript.0 == Some(0) {
+        return Err(ValidationError::ZeroSubscript(letter));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{
+        Degree, FunctionSymbol, Parser, PredicateLetter, SimpleStatementLetter, SingularTerm,
+        Subscript,
+    };
+
+    #[test]
+    fn accepts_valid_statements() {
+        let parser = Parser::new();
+
+        match parser.parse("{∀x((A¹x & B¹x) ⊃ ∀y(~C¹y ⊃ ∃z(A²zy & B¹z))), F²ab, ∃x(x = a)}") {
+            Ok(parse_tree) => match parse_tree.0 {
+                crate::parser::Input::StatementSet(statements) => {
+                    for statement in &statements {
+                        assert_eq!(validate_statement(statement), Ok(()));
+                    }
+                }
+                _ => assert!(false),
+            },
+            Err(e) => assert!(false, format!("{}", e)),
+        }
+    }
+
+    #[test]
+    fn rejects_degree_mismatch() {
+        let predicate_letter = PredicateLetter('F', Subscript(None), Degree(2));
+
+        let statement = Statement::Singular(
+            predicate_letter.clone(),
+            vec![SingularTerm('a', Subscript(None))],
+        );
+
+        assert_eq!(
+            validate_statement(&statement),
+            Err(ValidationError::Parse(ParseErrorKind::DegreeMismatch {
+                predicate_letter,
+                terms: 1
+            }))
+        );
+    }
+
+    #[test]
+    fn rejects_variables_out_of_scope_or_already_bound() {
+        let x = Variable('x', Subscript(None));
+        let y = Variable('y', Subscript(None));
+
+        // ∃x(x = y)
+        let statement = Statement::Existential(
+            x.clone(),
+            Box::new(Formula::Identity(
+                Term::Variable(x.clone()),
+                Term::Variable(y.clone()),
+            )),
+        );
+
+        assert_eq!(
+            validate_statement(&statement),
+            Err(ValidationError::Parse(ParseErrorKind::VariableOutOfScope(y)))
+        );
+
+        // ∃x∀xF¹x
+        let statement = Statement::Existential(
+            x.clone(),
+            Box::new(Formula::Statement(Box::new(Statement::Universal(
+                x.clone(),
+                Box::new(Formula::Predicate(
+                    PredicateLetter('F', Subscript(None), Degree(1)),
+                    vec![Term::Variable(x.clone())],
+                )),
+            )))),
+        );
+
+        assert_eq!(
+            validate_statement(&statement),
+            Err(ValidationError::Parse(ParseErrorKind::VariableAlreadyBound(x)))
+        );
+    }
+
+    #[test]
+    fn rejects_function_terms() {
+        let x = Variable('x', Subscript(None));
+        let term = Term::Function(
+            FunctionSymbol('f', Subscript(None)),
+            vec![Term::Variable(x.clone())],
+        );
+
+        // ∀xF¹f(x)
+        let statement = Statement::Universal(
+            x,
+            Box::new(Formula::Predicate(
+                PredicateLetter('F', Subscript(None), Degree(1)),
+                vec![term.clone()],
+            )),
+        );
+
+        assert_eq!(
+            validate_statement(&statement),
+            Err(ValidationError::FunctionTermNotAllowed(term))
+        );
+    }
+
+    #[test]
+    fn rejects_statements_the_parser_cant_produce() {
+        let a = SingularTerm('a', Subscript(None));
+        let x = Variable('x', Subscript(None));
+
+        // F⁰, i.e. a singular statement without terms
+        let predicate_letter = PredicateLetter('F', Subscript(None), Degree(0));
+
+        assert_eq!(
+            validate_statement(&Statement::Singular(predicate_letter.clone(), vec![])),
+            Err(ValidationError::ZeroDegree(predicate_letter))
+        );
+
+        // F¹ with no terms
+        let predicate_letter = PredicateLetter('F', Subscript(None), Degree(1));
+
+        assert_eq!(
+            validate_statement(&Statement::Singular(predicate_letter.clone(), vec![])),
+            Err(ValidationError::Parse(ParseErrorKind::DegreeMismatch {
+                predicate_letter,
+                terms: 0
+            }))
+        );
+
+        // A₀
+        assert_eq!(
+            validate_statement(&Statement::Simple(SimpleStatementLetter(
+                'A',
+                Subscript(Some(0))
+            ))),
+            Err(ValidationError::ZeroSubscript('A'))
+        );
+
+        // ∀x₀F¹x₀
+        let x0 = Variable('x', Subscript(Some(0)));
+
+        assert_eq!(
+            validate_statement(&Statement::Universal(
+                x0.clone(),
+                Box::new(Formula::Predicate(
+                    PredicateLetter('F', Subscript(None), Degree(1)),
+                    vec![Term::Variable(x0)],
+                )),
+            )),
+            Err(ValidationError::ZeroSubscript('x'))
+        );
+
+        // ∀xF²xa₀
+        assert_eq!(
+            validate_statement(&Statement::Universal(
+                x.clone(),
+                Box::new(Formula::Predicate(
+                    PredicateLetter('F', Subscript(None), Degree(2)),
+                    vec![
+                        Term::Variable(x.clone()),
+                        Term::SingularTerm(SingularTerm('a', Subscript(Some(0)))),
+                    ],
+                )),
+            )),
+            Err(ValidationError::ZeroSubscript('a'))
+        );
+
+        // ∀x(F¹a & F¹x), where F¹a is a predicate rather than a statement
+        let formula = Formula::Predicate(
+            PredicateLetter('F', Subscript(None), Degree(1)),
+            vec![Term::SingularTerm(a.clone())],
+        );
+
+        assert_eq!(
+            validate_statement(&Statement::Universal(
+                x.clone(),
+                Box::new(Formula::Conjunction(
+                    Box::new(formula.clone()),
+                    Box::new(Formula::Predicate(
+                        PredicateLetter('F', Subscript(None), Degree(1)),
+                        vec![Term::Variable(x.clone())],
+                    )),
+                )),
+            )),
+            Err(ValidationError::FormulaWithoutVariable(formula))
+        );
+
+        // ∀x(a = a), where a = a is an identity rather than a statement
+        let formula = Formula::Identity(Term::SingularTerm(a.clone()), Term::SingularTerm(a));
+
+        assert_eq!(
+            validate_statement(&Statement::Universal(x, Box::new(formula.clone()))),
+            Err(ValidationError::FormulaWithoutVariable(formula))
+        );
+    }
+}
@@ -0,0 +1,133 @@
+use std::time::{Duration, Instant};
+
+/// Limits on how much work the truth tree algorithm may do before giving up.
+///
+/// Since the truth tree of a satisfiable set of statements may be infinite, without
+/// any limits the algorithm may never finish. When any of these limits is reached,
+/// the algorithm stops and the (partial) truth tree records why
+/// (see [TruthTree::interruption](struct.TruthTree.html#method.interruption)).
+///
+/// All limits are disabled by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum number of statements in the whole tree.
+    pub max_nodes: Option<usize>,
+    /// The maximum number of statements from the top of the tree to the end of any branch.
+    pub max_branch_depth: Option<usize>,
+    /// The maximum number of applications of the universal quantifier rule.
+    pub max_universal_instantiations: Option<usize>,
+    /// The maximum wall-clock time the algorithm may run for.
+    pub time_budget: Option<Duration>,
+}
+
+/// The reason why the truth tree algorithm stopped before finishing the tree.
+///
+/// **Serialization of this enum requires the feature `serde_support` to be enabled.**
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
+pub enum Interruption {
+    /// [Limits::max_nodes](struct.Limits.html#structfield.max_nodes) was reached.
+    NodeLimitReached,
+    /// [Limits::max_branch_depth](struct.Limits.html#structfield.max_branch_depth) was reached.
+    BranchDepthLimitReached,
+    /// [Limits::max_universal_instantiations](struct.Limits.html#structfield.max_universal_instantiations)
+    /// was reached.
+    UniversalInstantiationLimitReached,
+    /// [Limits::time_budget](struct.Limits.html#structfield.time_budget) ran out.
+    TimeBudgetExhausted,
+    /// The computation was cancelled through a [CancellationToken](struct.CancellationToken.html).
+    Cancelled,
+}
+
+// Keeps track of how much of the limits the algorithm has used up so far
+pub(super) struct Budget {
+    limits: Limits,
+    deadline: Option<Instant>, // Instant::now() isn't available on every platform (e.g. wasm),
+                               // so it's only ever called if there is a time budget
+    pub(super) nodes: usize,
+    pub(super) branch_depth: usize,
+    pub(super) universal_instantiations: usize,
+}
+
+impl Budget {
+    pub(super) fn new(limits: &Limits, initial_nodes: usize) -> Self {
+        Budget {
+            limits: limits.clone(),
+            deadline: limits.time_budget.map(|x| Instant::now() + x),
+            nodes: initial_nodes,
+            branch_depth: initial_nodes,
+            universal_instantiations: 0,
+        }
+    }
+
+    pub(super) fn exhausted(&self) -> Option<Interruption> {
+        let reached = |limit: Option<usize>, used: usize| limit.map_or(false, |x| used >= x);
+
+        if reached(self.limits.max_nodes, self.nodes) {
+            Some(Interruption::NodeLimitReached)
+        } else if reached(self.limits.max_branch_depth, self.branch_depth) {
+            Some(Interruption::BranchDepthLimitReached)
+        } else if reached(
+            self.limits.max_universal_instantiations,
+            self.universal_instantiations,
+        ) {
+            Some(Interruption::UniversalInstantiationLimitReached)
+        } else if self.deadline.map_or(false, |x| Instant::now() >= x) {
+            Some(Interruption::TimeBudgetExhausted)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn budget_without_limits_is_never_exhausted() {
+        let mut budget = Budget::new(&Limits::default(), 1);
+
+        budget.nodes = usize::MAX;
+        budget.branch_depth = usize::MAX;
+        budget.universal_instantiations = usize::MAX;
+
+        assert_eq!(budget.exhausted(), None);
+    }
+
+    #[test]
+    fn budget_is_exhausted_when_limit_reached() {
+        let mut budget = Budget::new(
+            &Limits {
+                max_nodes: Some(3),
+                max_universal_instantiations: Some(1),
+                ..Limits::default()
+            },
+            2,
+        );
+
+        assert_eq!(budget.exhausted(), None);
+
+        budget.universal_instantiations = 1;
+        assert_eq!(
+            budget.exhausted(),
+            Some(Interruption::UniversalInstantiationLimitReached)
+        );
+
+        budget.nodes = 3;
+        assert_eq!(budget.exhausted(), Some(Interruption::NodeLimitReached));
+    }
+
+    #[test]
+    fn budget_is_exhausted_when_time_runs_out() {
+        let budget = Budget::new(
+            &Limits {
+                time_budget: Some(Duration::from_millis(0)),
+                ..Limits::default()
+            },
+            1,
+        );
+
+        assert_eq!(budget.exhausted(), Some(Interruption::TimeBudgetExhausted));
+    }
+}
@@ -1,10 +1,19 @@
+mod cancellation;
+mod limits;
 mod rules;
 
+pub use self::cancellation::CancellationToken;
+pub use self::limits::{Interruption, Limits};
+pub(crate) use self::rules::first_unused_in_singular_term_stack;
+
+use self::limits::Budget;
 use super::truth_tree::*;
-use crate::parser::Statement;
+use crate::parser::{validate_statement, Statement, Term, ValidationError};
+use crate::visit::Visit;
 use snowflake::ProcessUniqueId;
 use std::cmp::Ordering;
-use std::collections::BinaryHeap;
+use std::collections::{BinaryHeap, VecDeque};
+use std::fmt;
 
 /// The ID of some derivation. A derivation is any application of some rule to some statement.
 ///
@@ -57,6 +66,55 @@ pub enum Rule {
     Conditional,
     NegationOfConjunction,
     Disjunction,
+    Biconditional,
+    NegationOfBiconditional,
+    Identity,
+}
+
+impl Rule {
+    /// Returns the short name of the rule that the book uses to justify
+    /// derived statements (e.g. `∨` for the disjunction rule).
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Rule::QuantifierExchange => "QE",
+            Rule::ExistentialQuantifier => "∃",
+            Rule::UniversalQuantifier => "∀",
+            Rule::DoubleNegation => "~~",
+            Rule::Conjunction => "&",
+            Rule::NegationOfConditional => "~⊃",
+            Rule::NegationOfDisjunction => "~∨",
+            Rule::Conditional => "⊃",
+            Rule::NegationOfConjunction => "~&",
+            Rule::Disjunction => "∨",
+            Rule::Biconditional => "≡",
+            Rule::NegationOfBiconditional => "~≡",
+            Rule::Identity => "=",
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Rule::QuantifierExchange => "Quantifier exchange",
+                Rule::ExistentialQuantifier => "Existential quantifier",
+                Rule::UniversalQuantifier => "Universal quantifier",
+                Rule::DoubleNegation => "Double negation",
+                Rule::Conjunction => "Conjunction",
+                Rule::NegationOfConditional => "Negation of conditional",
+                Rule::NegationOfDisjunction => "Negation of disjunction",
+                Rule::Conditional => "Conditional",
+                Rule::NegationOfConjunction => "Negation of conjunction",
+                Rule::Disjunction => "Disjunction",
+                Rule::Biconditional => "Biconditional",
+                Rule::NegationOfBiconditional => "Negation of biconditional",
+                Rule::Identity => "Identity",
+            }
+        )
+    }
 }
 
 #[derive(PartialEq, Eq, Debug, Clone)]
@@ -109,7 +167,17 @@ impl Ord for QueueEntry {
         // remains, but at least we can be certain that if we have an
         // unsatisfiable initial set of statements, then our algorithm
         // will generate a closed tree.
-        let rule_priority_order: [Rule; 10] = [
+        // 5. Identity (substitution of identicals) is, like UQ, a rule that
+        // may be applied over and over, but since it never introduces new
+        // singular terms, it should come before UQ.
+        // 6. Regardless of all of the above, entries whose rule failed
+        // the last time they were tried come after all other entries.
+        // Otherwise, a repeatable rule that can't be applied could be
+        // popped over and over again, never letting other rules be tried.
+        // 7. The biconditional rules branch into two branches with two
+        // statements each, which makes them the most expensive rules to
+        // apply, so they come after all other branching rules.
+        let rule_priority_order: [Rule; 13] = [
             Rule::DoubleNegation,
             Rule::Conjunction,
             Rule::NegationOfConditional,
@@ -117,11 +185,20 @@ impl Ord for QueueEntry {
             Rule::Conditional,
             Rule::NegationOfConjunction,
             Rule::Disjunction,
+            Rule::Biconditional,
+            Rule::NegationOfBiconditional,
             Rule::QuantifierExchange,
             Rule::ExistentialQuantifier,
+            Rule::Identity,
             Rule::UniversalQuantifier,
         ];
 
+        match (self.failed_last, other.failed_last) {
+            (false, true) => return Ordering::Greater,
+            (true, false) => return Ordering::Less,
+            _ => {}
+        }
+
         match (&self.rule, &other.rule) {
             // Atomic statements should come first in the queue
             (Some(_), None) => Ordering::Less,
@@ -152,6 +229,8 @@ impl PartialOrd for QueueEntry {
 enum ApplyRuleWhatdo {
     AddToExistingBranches,
     AsNewBranches,
+    // Each consecutive group of N statements goes in a new branch of its own
+    AsNewBranchesInGroupsOf(usize),
 }
 
 struct RuleDeriveResult {
@@ -159,12 +238,36 @@ struct RuleDeriveResult {
     whatdo: ApplyRuleWhatdo,
 }
 
+/// The truth tree algorithm, run on some set of initial statements.
+///
+/// [StatementSet](struct.StatementSet.html), [Argument](struct.Argument.html) and
+/// [SingleStatement](struct.SingleStatement.html) use this to build their truth trees.
+/// It can be used directly to go through the construction of a truth tree step by step
+/// (see [steps](#method.steps)).
 pub struct TruthTreeMethod {
     tree: TruthTree,
+    limits: Limits,
+    cancellation: Option<CancellationToken>,
 }
 
 impl TruthTreeMethod {
+    /// Creates the truth tree algorithm for the initial statements `statements`.
+    ///
+    /// # Panics
+    /// Panics if some statement contains a function term (as
+    /// [Skolemization](normal_form/fn.skolemize.html) makes), which the truth tree rules
+    /// can't instantiate quantified statements with. See [try_new](#method.try_new) for a
+    /// version that fails instead.
     pub fn new(statements: &Vec<Statement>) -> Self {
+        for statement in statements {
+            let mut finder = FunctionTermFinder(None);
+            finder.visit_statement(statement);
+
+            if let Some(term) = finder.0 {
+                panic!("function term {} in initial statement", term);
+            }
+        }
+
         TruthTreeMethod {
             tree: TruthTree::new(Branch::new(
                 statements
@@ -175,12 +278,58 @@ impl TruthTreeMethod {
                     })
                     .collect(),
             )),
+            limits: Limits::default(),
+            cancellation: None,
         }
     }
 
-    pub fn compute(mut self) -> TruthTree {
+    /// Same as [new](#method.new), except that it fails if some statement is semantically
+    /// invalid, the same way [StatementSet::new](struct.StatementSet.html#method.new) does
+    /// (e.g. if it contains some function term), rather than panicking.
+    pub fn try_new(statements: &Vec<Statement>) -> Result<Self, ValidationError> {
+        for statement in statements {
+            validate_statement(statement)?;
+        }
+
+        Ok(TruthTreeMethod::new(statements))
+    }
+
+    /// Sets the limits on how much work the algorithm may do. See [Limits](struct.Limits.html).
+    pub fn with_limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Allows the algorithm to be cancelled through `token`. See [CancellationToken](struct.CancellationToken.html).
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Runs the truth tree algorithm to the end (or until it is interrupted), and returns
+    /// the resulting truth tree.
+    pub fn compute(self) -> TruthTree {
+        let mut steps = self.steps();
+
+        while steps.next().is_some() {}
+
+        steps.into_tree()
+    }
+
+    /// Returns an iterator over the steps of the truth tree algorithm, which applies
+    /// one rule at a time. Running the iterator to the end builds the exact same truth tree
+    /// as [compute](#method.compute).
+    pub fn steps(self) -> Steps {
         let mut queue = BinaryHeap::new();
 
+        let budget = Budget::new(
+            &self.limits,
+            self.tree
+                .branch_from_id(&self.tree.main_trunk_id())
+                .statements()
+                .count(),
+        );
+
         // Populate the queue with the main trunk
         for (statement_id, branch_node) in self
             .tree
@@ -196,76 +345,196 @@ impl TruthTreeMethod {
             });
         }
 
+        Steps {
+            method: self,
+            queue,
+            budget,
+            pending: VecDeque::new(),
+            finished: false,
+        }
+    }
+
+    fn branch_depth(&self, branch_id: &TreeId) -> usize {
+        // Number of statements from the top of the tree to the end of branch_id
+        self.tree
+            .traverse_upwards_branches(branch_id)
+            .map(|(_, branch)| branch.statements().count())
+            .sum()
+    }
+
+    fn find_contradiction(
+        &self,
+        statement_id: &TreeId,
+        statement: &Statement,
+        branch_id: &TreeId,
+    ) -> Option<(BranchNodeLocation, BranchNodeLocation)> {
+        // Returns the location of the statement that 'statement' contradicts,
+        // followed by the location of 'statement' itself
+
+        // Usually, you'd think to only iterate towards the root of the tree
+        // to find a contradiction, however, it's easier if we accept the entire
+        // branch the statement is on. After all, if there is a contradiction
+        // anywhere in the branch, whether it be above or below (which can't really
+        // happen in the current state of things of 'compute' anyway), the branch will
+        // close.
+
+        let statement_location = BranchNodeLocation {
+            node_id: statement_id.clone(),
+            branch_id: branch_id.clone(),
+        };
+
+        // A statement of the form '~(a = a)' contradicts itself
+        if let Statement::LogicalNegation(ref rst) = statement {
+            if let Statement::Identity(ref lterm, ref rterm) = **rst {
+                if lterm == rterm {
+                    return Some((statement_location.clone(), statement_location));
+                }
+            }
+        }
+
+        for (ancestor_branch_id, ancestor_branch) in self.tree.traverse_upwards_branches(&branch_id)
+        {
+            for (node_id, branch_node) in ancestor_branch.statements() {
+                let contradicts = match (&branch_node.statement, statement) {
+                    (Statement::LogicalNegation(ref a), ref b @ _) => **a == **b,
+                    (ref a @ _, Statement::LogicalNegation(ref b)) => **a == **b,
+                    _ => false,
+                };
+
+                if contradicts {
+                    return Some((
+                        BranchNodeLocation {
+                            node_id,
+                            branch_id: ancestor_branch_id,
+                        },
+                        statement_location,
+                    ));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// A step of the truth tree algorithm. See [TruthTreeMethod::steps](struct.TruthTreeMethod.html#method.steps).
+#[derive(Clone, Debug)]
+pub enum Step {
+    /// `rule` was applied to the statement at `location`, on every open branch under it.
+    RuleApplied {
+        location: BranchNodeLocation,
+        rule: Rule,
+    },
+    /// A statement derived by the last rule applied was added to the tree.
+    StatementAdded(BranchNodeLocation),
+    /// A new branch was added to the tree by the last rule applied.
+    BranchCreated(TreeId),
+    /// A branch was closed.
+    BranchClosed(TreeId),
+}
+
+/// An iterator over the steps of the truth tree algorithm.
+/// See [TruthTreeMethod::steps](struct.TruthTreeMethod.html#method.steps).
+pub struct Steps {
+    method: TruthTreeMethod,
+    queue: BinaryHeap<QueueEntry>,
+    budget: Budget,
+    pending: VecDeque<Step>,
+    finished: bool,
+}
+
+impl Steps {
+    /// Returns the truth tree as it is after the last step.
+    pub fn tree(&self) -> &TruthTree {
+        &self.method.tree
+    }
+
+    /// Returns the truth tree as it is after the last step. If the iterator hasn't
+    /// been run to the end, the truth tree is not finished.
+    pub fn into_tree(self) -> TruthTree {
+        self.method.tree
+    }
+
+    // Takes one statement off the queue and applies whatever rule applies to it,
+    // recording the changes to the tree in self.pending
+    fn advance(&mut self) {
         // All nodes on the queue are already on the tree
         // A node represents some statement that needs to have a rule applied to it
-        'outer: while let Some(QueueEntry {
+        let QueueEntry {
             statement_id,
             statement,
             rule,
             branch_id,
             mut failed_last,
-        }) = queue.pop()
-        {
-            match rule {
-                Some((rule, repeat)) => {
-                    // Open child branches of branch where original statement is
-                    // This reflects the open branches BEFORE we added the results
-                    // of the application of rules.
-                    let open_branches_ids = self
-                        .tree
-                        .traverse_downwards_branches_ids(&branch_id)
-                        .filter(|x| {
-                            !self.tree.branch_from_id(&x).is_closed()
-                                && self.tree.branch_is_last_child(&x)
-                        })
-                        .collect::<Vec<_>>();
-
-                    if open_branches_ids.len() == 0 {
-                        continue;
-                    }
+        } = match self.queue.pop() {
+            Some(entry) => entry,
+            None => {
+                self.finished = true;
+                return;
+            }
+        };
+
+        // There is still work left to do, but if it was cancelled or it goes over
+        // the limits, the tree is left as it is
+        let interruption = match &self.method.cancellation {
+            Some(token) if token.is_cancelled() => Some(Interruption::Cancelled),
+            _ => self.budget.exhausted(),
+        };
+
+        if let Some(interruption) = interruption {
+            self.method.tree.interrupt(interruption);
+            self.finished = true;
+            return;
+        }
 
-                    // Generate a unique ID for all resulting statements
-                    // (to identify them as resulting from the same application of a rule)
-                    let mut derivation_id = DerivationId {
-                        id: ProcessUniqueId::new(),
-                        index: 0u64,
-                    };
-
-                    // Apply the rule to every open branch
-                    for child_branch_id in open_branches_ids {
-                        match self.apply_rule(rule.clone(), &statement, &child_branch_id) {
-                            Some(result) => {
-                                for x in &result.statements {
-                                    let (derived_statement_id, derived_statement_branch_id) = {
-                                        match result.whatdo {
-                                            ApplyRuleWhatdo::AddToExistingBranches => {
-                                                // Add derived statement to all open child branches of branch_id
-                                                // at the end of the tree (i.e. child branches that have no children)
-                                                let new_statement_id = self
-                                                    .tree
-                                                    .branch_from_id_mut(&child_branch_id)
-                                                    .append_statement(BranchNode {
-                                                        statement: x.clone(),
-                                                        derived_from: Some((
-                                                            BranchNodeLocation {
-                                                                node_id: statement_id.clone(),
-                                                                branch_id: branch_id.clone(),
-                                                            },
-                                                            rule.clone(),
-                                                            derivation_id.clone(),
-                                                        )),
-                                                    });
-
-                                                // Each derived statement added to the same branch has a unique 'index'
-                                                derivation_id.index += 1;
-
-                                                (new_statement_id, child_branch_id.clone())
-                                            }
+        match rule {
+            Some((rule, repeat)) => {
+                // Open child branches of branch where original statement is
+                // This reflects the open branches BEFORE we added the results
+                // of the application of rules.
+                let open_branches_ids = self
+                    .method
+                    .tree
+                    .traverse_downwards_branches_ids(&branch_id)
+                    .filter(|x| {
+                        !self.method.tree.branch_from_id(&x).is_closed()
+                            && self.method.tree.branch_is_last_child(&x)
+                    })
+                    .collect::<Vec<_>>();
 
-                                            ApplyRuleWhatdo::AsNewBranches => {
-                                                // Each derived statement will create a new child branch on every open
-                                                // branch under branch_id that is at the end of the tree
-                                                let new_branch = Branch::new(vec![BranchNode {
+                if open_branches_ids.len() == 0 {
+                    return;
+                }
+
+                // Generate a unique ID for all resulting statements
+                // (to identify them as resulting from the same application of a rule)
+                let mut derivation_id = DerivationId {
+                    id: ProcessUniqueId::new(),
+                    index: 0u64,
+                };
+
+                // Whether the rule could be applied to at least one of the open branches
+                let mut applied = false;
+
+                // Apply the rule to every open branch
+                for child_branch_id in open_branches_ids {
+                    match self.method.apply_rule(rule.clone(), &statement, &child_branch_id) {
+                        Some(result) => {
+                            // The branch created by the first statement of the current group
+                            // (only used by AsNewBranchesInGroupsOf)
+                            let mut group_branch_id: Option<TreeId> = None;
+
+                            for (i, x) in result.statements.iter().enumerate() {
+                                let (derived_statement_id, derived_statement_branch_id) = {
+                                    match result.whatdo {
+                                        ApplyRuleWhatdo::AddToExistingBranches => {
+                                            // Add derived statement to all open child branches of branch_id
+                                            // at the end of the tree (i.e. child branches that have no children)
+                                            let new_statement_id = self
+                                                .method
+                                                .tree
+                                                .branch_from_id_mut(&child_branch_id)
+                                                .append_statement(BranchNode {
                                                     statement: x.clone(),
                                                     derived_from: Some((
                                                         BranchNodeLocation {
@@ -275,148 +544,263 @@ impl TruthTreeMethod {
                                                         rule.clone(),
                                                         derivation_id.clone(),
                                                     )),
-                                                }]);
+                                                });
+
+                                            // Each derived statement added to the same branch has a unique 'index'
+                                            derivation_id.index += 1;
+
+                                            (new_statement_id, child_branch_id.clone())
+                                        }
+
+                                        ApplyRuleWhatdo::AsNewBranches => {
+                                            // Each derived statement will create a new child branch on every open
+                                            // branch under branch_id that is at the end of the tree
+                                            let new_branch = Branch::new(vec![BranchNode {
+                                                statement: x.clone(),
+                                                derived_from: Some((
+                                                    BranchNodeLocation {
+                                                        node_id: statement_id.clone(),
+                                                        branch_id: branch_id.clone(),
+                                                    },
+                                                    rule.clone(),
+                                                    derivation_id.clone(),
+                                                )),
+                                            }]);
+
+                                            let root_statement_id =
+                                                new_branch.statement_ids().next().unwrap();
+
+                                            let new_branch_id = self
+                                                .method
+                                                .tree
+                                                .append_branch_at(new_branch, &child_branch_id);
+
+                                            self.pending
+                                                .push_back(Step::BranchCreated(new_branch_id.clone()));
+
+                                            (root_statement_id, new_branch_id.clone())
+                                        }
+
+                                        ApplyRuleWhatdo::AsNewBranchesInGroupsOf(group_len) => {
+                                            // Same as AsNewBranches, except the first statement of each
+                                            // group creates the new branch and the others are appended to it
+                                            // Statements within the same group have a unique 'index', which
+                                            // is mirrored across groups
+                                            derivation_id.index = (i % group_len) as u64;
+
+                                            let new_node = BranchNode {
+                                                statement: x.clone(),
+                                                derived_from: Some((
+                                                    BranchNodeLocation {
+                                                        node_id: statement_id.clone(),
+                                                        branch_id: branch_id.clone(),
+                                                    },
+                                                    rule.clone(),
+                                                    derivation_id.clone(),
+                                                )),
+                                            };
+
+                                            if i % group_len == 0 {
+                                                let new_branch = Branch::new(vec![new_node]);
 
                                                 let root_statement_id =
                                                     new_branch.statement_ids().next().unwrap();
 
                                                 let new_branch_id = self
+                                                    .method
                                                     .tree
                                                     .append_branch_at(new_branch, &child_branch_id);
 
-                                                (root_statement_id, new_branch_id.clone())
+                                                self.pending
+                                                    .push_back(Step::BranchCreated(new_branch_id.clone()));
+
+                                                group_branch_id = Some(new_branch_id.clone());
+
+                                                (root_statement_id, new_branch_id)
+                                            } else {
+                                                let new_branch_id =
+                                                    group_branch_id.clone().unwrap();
+
+                                                let new_statement_id = self
+                                                    .method
+                                                    .tree
+                                                    .branch_from_id_mut(&new_branch_id)
+                                                    .append_statement(new_node);
+
+                                                (new_statement_id, new_branch_id)
                                             }
                                         }
-                                    };
-
-                                    // Add derived statement to queue for further processing
-                                    let new_node = QueueEntry {
-                                        statement_id: derived_statement_id,
-                                        statement: x.clone(),
-                                        rule: self.matches_some_rule(&x),
-                                        branch_id: derived_statement_branch_id.clone(),
-                                        failed_last: false,
-                                    };
-                                    queue.push(new_node);
-                                }
-
-                                derivation_id.index = 0;
-
-                                failed_last = false;
-
-                                // A new node was added to the tree
-                                // This invalidates the failed_last state, since we don't know if rules on the queue
-                                // might apply to any potential new singular terms
-                                // If we didn't invalidate the failed_last state, we could have a situation where:
-                                // tried all uq rules, failed
-                                // failed_last = true on all uq rules
-                                // some other rule then succeeded
-                                // rule pushed off the queue
-                                // compute() iterates over remaining uq rules, one of them doesn't apply
-                                // and since failed_last = true, it fails, even though there are other rules
-                                // to try
-                                queue = BinaryHeap::from(
-                                    queue
-                                        .into_vec()
-                                        .iter()
-                                        .map(|x| QueueEntry {
-                                            failed_last: false,
-                                            ..x.clone()
-                                        })
-                                        .collect::<Vec<_>>(),
-                                );
-                                // FIXME Is there a more efficient way (if we're thinking about that)
-                                // to do this other than using RefCell (mutating the contents of
-                                // the queue)?
-                            }
-                            None => {
-                                // Rule didn't need to be applied
-                                if repeat {
-                                    // Some rules can be reapplied over and over (i.e. UQ)
-                                    // If this is the case, we readd this node to the queue
-
-                                    // However, if we don't do anything else, we will run into an infinite
-                                    // loop when the tree doesn't close.
-
-                                    // Check if we haven't tried every other rule and some didn't fail
-                                    // If they all failed, we might as well stop now
-                                    if !queue.iter().any(|x| !x.failed_last) {
-                                        break 'outer;
                                     }
-                                }
-
-                                // Since we've reached here, there are other rules that may yet be applied
-                                // What happens now is the algorithm iterates over the queue, and if no
-                                // other rule can be applied, the code above will break the loop and finish
-                                // For now, let us tell the algorithm at least this rule failed
-
-                                // Important: we won't run into an infinite loop where there are branching rules
-                                // to be applied but that can't because the queue pops UQ first all the time,
-                                // because the impl for the Ord trait on QueueEntry makes sure that if
-                                // the rule has failed_last set, it'll always come after ALL other rules.
-                                // Since UQ is the only repeat rule, we're safe
-                                failed_last = true;
+                                };
+
+                                self.pending.push_back(Step::StatementAdded(BranchNodeLocation {
+                                    node_id: derived_statement_id.clone(),
+                                    branch_id: derived_statement_branch_id.clone(),
+                                }));
+
+                                // Add derived statement to queue for further processing
+                                let new_node = QueueEntry {
+                                    statement_id: derived_statement_id,
+                                    statement: x.clone(),
+                                    rule: self.method.matches_some_rule(&x),
+                                    branch_id: derived_statement_branch_id.clone(),
+                                    failed_last: false,
+                                };
+                                self.queue.push(new_node);
+
+                                self.budget.nodes += 1;
+                                self.budget.branch_depth = self
+                                    .budget
+                                    .branch_depth
+                                    .max(self.method.branch_depth(&derived_statement_branch_id));
                             }
+
+                            derivation_id.index = 0;
+
+                            applied = true;
+                            failed_last = false;
+
+                            // A new node was added to the tree
+                            // This invalidates the failed_last state, since we don't know if rules on the queue
+                            // might apply to any potential new singular terms
+                            // If we didn't invalidate the failed_last state, we could have a situation where:
+                            // tried all uq rules, failed
+                            // failed_last = true on all uq rules
+                            // some other rule then succeeded
+                            // rule pushed off the queue
+                            // compute() iterates over remaining uq rules, one of them doesn't apply
+                            // and since failed_last = true, it fails, even though there are other rules
+                            // to try
+                            self.queue = self
+                                .queue
+                                .drain()
+                                .map(|x| QueueEntry {
+                                    failed_last: false,
+                                    ..x
+                                })
+                                .collect();
+                            // FIXME Is there a more efficient way (if we're thinking about that)
+                            // to do this other than using RefCell (mutating the contents of
+                            // the queue)?
+                        }
+                        None => {
+                            // Rule didn't need to be applied
+                            // Let us tell the algorithm at least this rule failed
+                            failed_last = true;
                         }
                     }
+                }
 
-                    if repeat {
-                        // See right above, if we reached here, we're safe
-                        queue.push(QueueEntry {
-                            statement_id,
-                            statement,
-                            rule: Some((rule.clone(), repeat)),
-                            branch_id,
-                            failed_last: failed_last,
-                        });
+                if applied {
+                    // Nothing was pending before this statement was taken off the queue,
+                    // so this comes before the changes made by the rule
+                    self.pending.push_front(Step::RuleApplied {
+                        location: BranchNodeLocation {
+                            node_id: statement_id.clone(),
+                            branch_id: branch_id.clone(),
+                        },
+                        rule: rule.clone(),
+                    });
+
+                    if rule == Rule::UniversalQuantifier {
+                        self.budget.universal_instantiations += 1;
                     }
                 }
-                None => {
-                    // No rule to apply (statement is already atomic formula),
-                    // statement is already on tree, so we do nothing here
-                    // except checking for contradiction
-                    if self.statement_is_contradiction(&statement, &branch_id) {
-                        self.tree.branch_from_id_mut(&branch_id).close();
-                        continue;
+
+                if repeat {
+                    // Some rules can be reapplied over and over (i.e. UQ and identity)
+                    // If this is the case, we readd this node to the queue
+
+                    // However, if we don't do anything else, we will run into an infinite
+                    // loop when the tree doesn't close.
+
+                    // If the rule couldn't be applied to any of the open branches, check if
+                    // we haven't tried every other rule and some didn't fail
+                    // If they all failed, we might as well stop now
+                    if !applied && !self.queue.iter().any(|x| !x.failed_last) {
+                        self.finished = true;
+                        return;
                     }
+
+                    // Since we've reached here, there are other rules that may yet be applied
+                    // What happens now is the algorithm iterates over the queue, and if no
+                    // other rule can be applied, the code above will break the loop and finish
+
+                    // Important: we won't run into an infinite loop where there are other rules
+                    // to be applied but that can't because the queue pops the same repeat rule
+                    // first all the time, because the impl for the Ord trait on QueueEntry makes
+                    // sure that if the rule has failed_last set, it'll always come after ALL other rules.
+                    self.queue.push(QueueEntry {
+                        statement_id,
+                        statement,
+                        rule: Some((rule.clone(), repeat)),
+                        branch_id,
+                        failed_last: failed_last,
+                    });
                 }
             }
+            None => {
+                // No rule to apply (statement is already atomic formula),
+                // statement is already on tree, so we do nothing here
+                // except checking for contradiction
+                if let Some(closed_by) =
+                    self.method
+                        .find_contradiction(&statement_id, &statement, &branch_id)
+                {
+                    self.method
+                        .tree
+                        .branch_from_id_mut(&branch_id)
+                        .close(closed_by);
+                    self.pending.push_back(Step::BranchClosed(branch_id));
+                    return;
+                }
 
-            // Original rules don't need to be marked done
-            // The algorithm doesn't need it, and you can know which are 'done'
-            // by checking the IDs that statements derive from
+                // Identity statements are atomic, but they also allow substituting
+                // one singular term for the other in every other atomic statement
+                // on the branch. That is done through a separate entry, so that
+                // the identity statement itself is still checked for contradictions
+                // as soon as it's added to the tree, like any other atomic statement
+                if self.method.can_apply_identity_rule(&statement) {
+                    self.queue.push(QueueEntry {
+                        statement_id,
+                        statement,
+                        rule: Some((Rule::Identity, true)),
+                        branch_id,
+                        failed_last: false,
+                    });
+                }
+            }
         }
 
-        self.tree
+        // Original rules don't need to be marked done
+        // The algorithm doesn't need it, and you can know which are 'done'
+        // by checking the IDs that statements derive from
     }
+}
 
-    fn statement_is_contradiction(&self, statement: &Statement, branch_id: &TreeId) -> bool {
-        // Usually, you'd think to only iterate towards the root of the tree
-        // to find a contradiction, however, it's easier if we accept the entire
-        // branch the statement is on. After all, if there is a contradiction
-        // anywhere in the branch, whether it be above or below (which can't really
-        // happen in the current state of things of 'compute' anyway), the branch will
-        // close.
+impl Iterator for Steps {
+    type Item = Step;
 
-        for (_, ancestor_branch) in self.tree.traverse_upwards_branches(&branch_id) {
-            for (_, branch_node) in ancestor_branch.statements() {
-                match (&branch_node.statement, statement) {
-                    (Statement::LogicalNegation(ref a), ref b @ _) => {
-                        if **a == **b {
-                            return true;
-                        }
-                    }
-                    (ref a @ _, Statement::LogicalNegation(ref b)) => {
-                        if **a == **b {
-                            return true;
-                        }
-                    }
-                    _ => {}
-                }
-            }
+    fn next(&mut self) -> Option<Step> {
+        // Not every statement taken off the queue changes the tree
+        while self.pending.is_empty() && !self.finished {
+            self.advance();
         }
 
-        false
+        self.pending.pop_front()
+    }
+}
+
+// Finds the first function term, which the rules can't instantiate quantified statements with
+struct FunctionTermFinder(Option<Term>);
+
+impl Visit for FunctionTermFinder {
+    fn visit_term(&mut self, term: &Term) {
+        if let Term::Function(_, _) = term {
+            if self.0.is_none() {
+                self.0 = Some(term.clone());
+            }
+        }
     }
 }
 
@@ -424,8 +808,8 @@ impl TruthTreeMethod {
 mod tests {
     use super::*;
     use crate::parser::{
-        Degree, Formula, PredicateLetter, SimpleStatementLetter, SingularTerm, Statement,
-        Subscript, Term, Variable,
+        Degree, Formula, FunctionSymbol, PredicateLetter, SimpleStatementLetter, SingularTerm,
+        Statement, Subscript, Term, Variable,
     };
 
     #[test]
@@ -520,6 +904,30 @@ mod tests {
             failed_last: false,
         });
 
+        queue.push(QueueEntry {
+            statement_id: mock_id.clone(),
+            statement: mock_statement.clone(),
+            rule: Some((Rule::Identity, true)),
+            branch_id: mock_id.clone(),
+            failed_last: false,
+        });
+
+        queue.push(QueueEntry {
+            statement_id: mock_id.clone(),
+            statement: mock_statement.clone(),
+            rule: Some((Rule::NegationOfBiconditional, false)),
+            branch_id: mock_id.clone(),
+            failed_last: false,
+        });
+
+        queue.push(QueueEntry {
+            statement_id: mock_id.clone(),
+            statement: mock_statement.clone(),
+            rule: Some((Rule::Biconditional, false)),
+            branch_id: mock_id.clone(),
+            failed_last: false,
+        });
+
         assert_eq!(
             queue.pop().unwrap().rule,
             Some((Rule::DoubleNegation, false))
@@ -540,6 +948,14 @@ mod tests {
             Some((Rule::NegationOfConjunction, false))
         );
         assert_eq!(queue.pop().unwrap().rule, Some((Rule::Disjunction, false)));
+        assert_eq!(
+            queue.pop().unwrap().rule,
+            Some((Rule::Biconditional, false))
+        );
+        assert_eq!(
+            queue.pop().unwrap().rule,
+            Some((Rule::NegationOfBiconditional, false))
+        );
         assert_eq!(
             queue.pop().unwrap().rule,
             Some((Rule::QuantifierExchange, false))
@@ -548,6 +964,7 @@ mod tests {
             queue.pop().unwrap().rule,
             Some((Rule::ExistentialQuantifier, false))
         );
+        assert_eq!(queue.pop().unwrap().rule, Some((Rule::Identity, true)));
         assert_eq!(
             queue.pop().unwrap().rule,
             Some((Rule::UniversalQuantifier, true))
@@ -555,31 +972,349 @@ mod tests {
     }
 
     #[test]
-    fn statement_is_contradiction() {
+    fn queue_entry_that_failed_last_comes_last() {
+        let branch = Branch::new(vec![BranchNode {
+            statement: Statement::Simple(SimpleStatementLetter('A', Subscript(None))),
+            derived_from: None,
+        }]);
+        let mock_id = branch.statement_ids().next().unwrap();
+        let mock_statement = Statement::Simple(SimpleStatementLetter('A', Subscript(None)));
+
+        let mut queue = BinaryHeap::new();
+
+        queue.push(QueueEntry {
+            statement_id: mock_id.clone(),
+            statement: mock_statement.clone(),
+            rule: Some((Rule::Identity, true)),
+            branch_id: mock_id.clone(),
+            failed_last: true,
+        });
+
+        queue.push(QueueEntry {
+            statement_id: mock_id.clone(),
+            statement: mock_statement.clone(),
+            rule: Some((Rule::UniversalQuantifier, true)),
+            branch_id: mock_id.clone(),
+            failed_last: false,
+        });
+
+        assert_eq!(
+            queue.pop().unwrap().rule,
+            Some((Rule::UniversalQuantifier, true))
+        );
+        assert_eq!(queue.pop().unwrap().rule, Some((Rule::Identity, true)));
+    }
+
+    #[test]
+    fn find_contradiction() {
         let mut truth_tree_method = TruthTreeMethod::new(&vec![Statement::Simple(
             SimpleStatementLetter('A', Subscript(None)),
         )]);
 
+        let main_trunk_id = truth_tree_method.tree.main_trunk_id();
+        let trunk_statement_id = truth_tree_method
+            .tree
+            .branch_from_id(&main_trunk_id)
+            .statement_ids()
+            .next()
+            .unwrap();
+
         let child_branch_id = truth_tree_method.tree.append_branch_at(
             Branch::new(vec![BranchNode {
                 statement: Statement::Simple(SimpleStatementLetter('B', Subscript(None))),
                 derived_from: None,
             }]),
-            &truth_tree_method.tree.main_trunk_id(),
+            &main_trunk_id,
+        );
+        let child_statement_id = truth_tree_method
+            .tree
+            .branch_from_id(&child_branch_id)
+            .statement_ids()
+            .next()
+            .unwrap();
+
+        assert_eq!(
+            truth_tree_method.find_contradiction(
+                &child_statement_id,
+                &Statement::LogicalNegation(Box::new(Statement::Simple(SimpleStatementLetter(
+                    'A',
+                    Subscript(None)
+                ),))),
+                &child_branch_id
+            ),
+            Some((
+                BranchNodeLocation {
+                    node_id: trunk_statement_id,
+                    branch_id: main_trunk_id,
+                },
+                BranchNodeLocation {
+                    node_id: child_statement_id.clone(),
+                    branch_id: child_branch_id.clone(),
+                }
+            ))
+        );
+
+        assert_eq!(
+            truth_tree_method.find_contradiction(
+                &child_statement_id,
+                &Statement::Simple(SimpleStatementLetter('A', Subscript(None)),),
+                &child_branch_id
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn find_contradiction_negation_of_self_identity() {
+        let truth_tree_method = TruthTreeMethod::new(&vec![Statement::Simple(
+            SimpleStatementLetter('A', Subscript(None)),
+        )]);
+
+        let main_trunk_id = truth_tree_method.tree.main_trunk_id();
+        let statement_id = truth_tree_method
+            .tree
+            .branch_from_id(&main_trunk_id)
+            .statement_ids()
+            .next()
+            .unwrap();
+
+        let statement_location = BranchNodeLocation {
+            node_id: statement_id.clone(),
+            branch_id: main_trunk_id.clone(),
+        };
+
+        assert_eq!(
+            truth_tree_method.find_contradiction(
+                &statement_id,
+                &Statement::LogicalNegation(Box::new(Statement::Identity(
+                    SingularTerm('a', Subscript(None)),
+                    SingularTerm('a', Subscript(None))
+                ))),
+                &main_trunk_id
+            ),
+            Some((statement_location.clone(), statement_location))
         );
 
-        assert!(truth_tree_method.statement_is_contradiction(
-            &Statement::LogicalNegation(Box::new(Statement::Simple(SimpleStatementLetter(
+        assert_eq!(
+            truth_tree_method.find_contradiction(
+                &statement_id,
+                &Statement::LogicalNegation(Box::new(Statement::Identity(
+                    SingularTerm('a', Subscript(None)),
+                    SingularTerm('b', Subscript(None))
+                ))),
+                &main_trunk_id
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn substitutes_identicals() {
+        // a = b, b = c, F¹a ∴ F¹c
+        let truth_tree = TruthTreeMethod::new(&vec![
+            Statement::Identity(
+                SingularTerm('a', Subscript(None)),
+                SingularTerm('b', Subscript(None)),
+            ),
+            Statement::Identity(
+                SingularTerm('b', Subscript(None)),
+                SingularTerm('c', Subscript(None)),
+            ),
+            Statement::Singular(
+                PredicateLetter('F', Subscript(None), Degree(1)),
+                vec![SingularTerm('a', Subscript(None))],
+            ),
+            Statement::LogicalNegation(Box::new(Statement::Singular(
+                PredicateLetter('F', Subscript(None), Degree(1)),
+                vec![SingularTerm('c', Subscript(None))],
+            ))),
+        ])
+        .compute();
+
+        assert!(!truth_tree.is_open());
+
+        // a = b, F¹a ∴ F¹c
+        let truth_tree = TruthTreeMethod::new(&vec![
+            Statement::Identity(
+                SingularTerm('a', Subscript(None)),
+                SingularTerm('b', Subscript(None)),
+            ),
+            Statement::Singular(
+                PredicateLetter('F', Subscript(None), Degree(1)),
+                vec![SingularTerm('a', Subscript(None))],
+            ),
+            Statement::LogicalNegation(Box::new(Statement::Singular(
+                PredicateLetter('F', Subscript(None), Degree(1)),
+                vec![SingularTerm('c', Subscript(None))],
+            ))),
+        ])
+        .compute();
+
+        assert!(truth_tree.is_open());
+    }
+
+    #[test]
+    fn identity_is_reflexive() {
+        // ∴ ∀x(x = x)
+        let truth_tree = TruthTreeMethod::new(&vec![Statement::LogicalNegation(Box::new(
+            Statement::Universal(
+                Variable('x', Subscript(None)),
+                Box::new(Formula::Identity(
+                    Term::Variable(Variable('x', Subscript(None))),
+                    Term::Variable(Variable('x', Subscript(None))),
+                )),
+            ),
+        ))])
+        .compute();
+
+        assert!(!truth_tree.is_open());
+    }
+
+    #[test]
+    fn biconditional_branches_in_pairs() {
+        // (A ≡ B), ~A
+        let truth_tree = TruthTreeMethod::new(&vec![
+            Statement::LogicalBiconditional(
+                Box::new(Statement::Simple(SimpleStatementLetter(
+                    'A',
+                    Subscript(None),
+                ))),
+                Box::new(Statement::Simple(SimpleStatementLetter(
+                    'B',
+                    Subscript(None),
+                ))),
+            ),
+            Statement::LogicalNegation(Box::new(Statement::Simple(SimpleStatementLetter(
                 'A',
-                Subscript(None)
-            ),))),
-            &child_branch_id
-        ));
-
-        assert!(!truth_tree_method.statement_is_contradiction(
-            &Statement::Simple(SimpleStatementLetter('A', Subscript(None)),),
-            &child_branch_id
-        ));
+                Subscript(None),
+            )))),
+        ])
+        .compute();
+
+        let children = truth_tree
+            .traverse_branch_direct_descendants_ids(&truth_tree.main_trunk_id())
+            .collect::<Vec<_>>();
+
+        assert_eq!(children.len(), 2);
+
+        let left = truth_tree.branch_from_id(&children[0]);
+        let right = truth_tree.branch_from_id(&children[1]);
+
+        assert_eq!(left.statements().count(), 2);
+        assert_eq!(right.statements().count(), 2);
+        assert!(left.is_closed());
+        assert!(!right.is_closed());
+
+        // The left branch closes because of A, which contradicts ~A on the main trunk
+        let (first, second) = left.closed_by().unwrap();
+        assert_eq!(first.branch_id, truth_tree.main_trunk_id());
+        assert_eq!(second.branch_id, children[0]);
+        assert_eq!(
+            left.statement_from_id(&second.node_id).statement,
+            Statement::Simple(SimpleStatementLetter('A', Subscript(None)))
+        );
+        assert_eq!(right.closed_by(), None);
+
+        // Statements in each group mirror each other's indices
+        let indices = |branch: &Branch| {
+            branch
+                .statements()
+                .map(|(_, x)| x.derived_from.as_ref().unwrap().2.index)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(indices(left), vec![0, 1]);
+        assert_eq!(indices(right), vec![0, 1]);
+    }
+
+    #[test]
+    fn steps_build_same_tree_as_compute() {
+        // (A ≡ B), ~A
+        let statements = vec![
+            Statement::LogicalBiconditional(
+                Box::new(Statement::Simple(SimpleStatementLetter(
+                    'A',
+                    Subscript(None),
+                ))),
+                Box::new(Statement::Simple(SimpleStatementLetter(
+                    'B',
+                    Subscript(None),
+                ))),
+            ),
+            Statement::LogicalNegation(Box::new(Statement::Simple(SimpleStatementLetter(
+                'A',
+                Subscript(None),
+            )))),
+        ];
+
+        let mut steps = TruthTreeMethod::new(&statements).steps();
+
+        let kinds = steps
+            .by_ref()
+            .map(|x| match x {
+                Step::RuleApplied { rule, .. } => {
+                    assert_eq!(rule, Rule::Biconditional);
+                    "rule applied"
+                }
+                Step::StatementAdded(_) => "statement added",
+                Step::BranchCreated(_) => "branch created",
+                Step::BranchClosed(_) => "branch closed",
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            kinds,
+            vec![
+                "rule applied",
+                "branch created",
+                "statement added",
+                "statement added",
+                "branch created",
+                "statement added",
+                "statement added",
+                "branch closed",
+            ]
+        );
+
+        let shape = |truth_tree: &TruthTree| {
+            truth_tree
+                .traverse_downwards_branches(&truth_tree.main_trunk_id())
+                .map(|(_, branch)| {
+                    (
+                        branch
+                            .statements()
+                            .map(|(_, x)| x.statement.clone())
+                            .collect::<Vec<_>>(),
+                        branch.is_closed(),
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            shape(&steps.into_tree()),
+            shape(&TruthTreeMethod::new(&statements).compute())
+        );
+    }
+
+    #[test]
+    fn negation_of_biconditional_closes() {
+        // ~(A ≡ A)
+        let truth_tree = TruthTreeMethod::new(&vec![Statement::LogicalNegation(Box::new(
+            Statement::LogicalBiconditional(
+                Box::new(Statement::Simple(SimpleStatementLetter(
+                    'A',
+                    Subscript(None),
+                ))),
+                Box::new(Statement::Simple(SimpleStatementLetter(
+                    'A',
+                    Subscript(None),
+                ))),
+            ),
+        ))])
+        .compute();
+
+        assert!(!truth_tree.is_open());
     }
 
     #[test]
@@ -791,4 +1526,45 @@ mod tests {
 
         truth_tree_method.compute();
     }
+
+    #[test]
+    fn try_new_rejects_invalid_statements() {
+        let x = Variable('x', Subscript(None));
+        let term = Term::Function(
+            FunctionSymbol('f', Subscript(None)),
+            vec![Term::Variable(x.clone())],
+        );
+
+        // ∀xL²xf(x), as Skolemization makes of ∀x∃yL²xy
+        let statement = Statement::Universal(
+            x.clone(),
+            Box::new(Formula::Predicate(
+                PredicateLetter('L', Subscript(None), Degree(2)),
+                vec![Term::Variable(x), term.clone()],
+            )),
+        );
+
+        match TruthTreeMethod::try_new(&vec![statement]) {
+            Err(ValidationError::FunctionTermNotAllowed(t)) => assert_eq!(t, term),
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "function term f(x) in initial statement")]
+    fn panics_on_function_terms() {
+        let x = Variable('x', Subscript(None));
+
+        // ∀xF¹f(x)
+        TruthTreeMethod::new(&vec![Statement::Universal(
+            x.clone(),
+            Box::new(Formula::Predicate(
+                PredicateLetter('F', Subscript(None), Degree(1)),
+                vec![Term::Function(
+                    FunctionSymbol('f', Subscript(None)),
+                    vec![Term::Variable(x)],
+                )],
+            )),
+        )]);
+    }
 }
@@ -1,6 +1,7 @@
 use super::{ApplyRuleWhatdo, Rule, RuleDeriveResult, TruthTreeMethod};
 use crate::parser::{Formula, SingularTerm, Statement, Subscript, Term, Variable};
 use crate::validity::truth_tree::TreeId;
+use crate::visit::{Fold, Visit};
 use std::iter::once;
 
 impl TruthTreeMethod {
@@ -25,6 +26,10 @@ impl TruthTreeMethod {
             Some((Rule::NegationOfConjunction, false))
         } else if self.can_apply_disjunction_rule(&statement) {
             Some((Rule::Disjunction, false))
+        } else if self.can_apply_biconditional_rule(&statement) {
+            Some((Rule::Biconditional, false))
+        } else if self.can_apply_negation_of_biconditional_rule(&statement) {
+            Some((Rule::NegationOfBiconditional, false))
         } else {
             None
         }
@@ -115,6 +120,31 @@ impl TruthTreeMethod {
         }
     }
 
+    fn can_apply_biconditional_rule(&self, statement: &Statement) -> bool {
+        match statement {
+            Statement::LogicalBiconditional(_, _) => true,
+            _ => false,
+        }
+    }
+
+    fn can_apply_negation_of_biconditional_rule(&self, statement: &Statement) -> bool {
+        match statement {
+            Statement::LogicalNegation(ref rst) => match **rst {
+                Statement::LogicalBiconditional(_, _) => true,
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    pub(super) fn can_apply_identity_rule(&self, statement: &Statement) -> bool {
+        // 'a = a' can't be used to substitute anything for anything else
+        match statement {
+            Statement::Identity(ref lterm, ref rterm) => lterm != rterm,
+            _ => false,
+        }
+    }
+
     pub(super) fn apply_rule(
         &self,
         rule: Rule,
@@ -132,6 +162,9 @@ impl TruthTreeMethod {
             Rule::Conditional => self.apply_conditional_rule(&statement),
             Rule::NegationOfConjunction => self.apply_negation_of_conjunction_rule(&statement),
             Rule::Disjunction => self.apply_disjunction_rule(&statement),
+            Rule::Biconditional => self.apply_biconditional_rule(&statement),
+            Rule::NegationOfBiconditional => self.apply_negation_of_biconditional_rule(&statement),
+            Rule::Identity => self.apply_identity_rule(&statement, &branch_id),
         }
     }
 
@@ -163,7 +196,7 @@ impl TruthTreeMethod {
             Statement::Existential(_, _) => Some(RuleDeriveResult {
                 statements: vec![self.instantiate_quantified_statement(
                     &statement,
-                    &self.first_unused_in_singular_term_stack(
+                    &first_unused_in_singular_term_stack(
                         &self.build_singular_term_stack_for_branch(&branch_id),
                     ),
                 )],
@@ -187,7 +220,7 @@ impl TruthTreeMethod {
                 let stack = self.build_singular_term_stack_for_branch(&branch_id);
 
                 let new_singular_term = if stack.is_empty() {
-                    Some(self.first_unused_in_singular_term_stack(&stack))
+                    Some(first_unused_in_singular_term_stack(&stack))
                 } else {
                     stack.iter().cloned().find(|x| {
                         let instantiated_statement =
@@ -317,66 +350,156 @@ impl TruthTreeMethod {
         }
     }
 
-    fn build_singular_term_stack_for_branch(&self, branch_id: &TreeId) -> Vec<SingularTerm> {
-        let mut stack = Vec::new();
-
-        for (_, ancestor_branch) in self.tree.traverse_upwards_branches(&branch_id) {
-            for (_, branch_node) in ancestor_branch.statements() {
-                self.find_singular_terms_in_statement(&mut stack, &branch_node.statement);
-            }
+    fn apply_biconditional_rule(&self, statement: &Statement) -> Option<RuleDeriveResult> {
+        // Splits into one branch containing both sides and one containing
+        // the negation of both
+        match statement {
+            Statement::LogicalBiconditional(ref lst, ref rst) => Some(RuleDeriveResult {
+                statements: vec![
+                    *lst.clone(),
+                    *rst.clone(),
+                    Statement::LogicalNegation(lst.clone()),
+                    Statement::LogicalNegation(rst.clone()),
+                ],
+                whatdo: ApplyRuleWhatdo::AsNewBranchesInGroupsOf(2),
+            }),
+            _ => panic!("attempt to apply wrong rule to statement"),
         }
+    }
 
-        stack
+    fn apply_negation_of_biconditional_rule(
+        &self,
+        statement: &Statement,
+    ) -> Option<RuleDeriveResult> {
+        match statement {
+            Statement::LogicalNegation(ref rst) => match **rst {
+                Statement::LogicalBiconditional(ref inner_lst, ref inner_rst) => {
+                    Some(RuleDeriveResult {
+                        statements: vec![
+                            *inner_lst.clone(),
+                            Statement::LogicalNegation(inner_rst.clone()),
+                            Statement::LogicalNegation(inner_lst.clone()),
+                            *inner_rst.clone(),
+                        ],
+                        whatdo: ApplyRuleWhatdo::AsNewBranchesInGroupsOf(2),
+                    })
+                }
+                _ => panic!("attempt to apply wrong rule to statement"),
+            },
+            _ => panic!("attempt to apply wrong rule to statement"),
+        }
     }
 
-    fn find_singular_terms_in_statement(
+    fn apply_identity_rule(
         &self,
-        mut stack: &mut Vec<SingularTerm>,
         statement: &Statement,
-    ) {
+        branch_id: &TreeId,
+    ) -> Option<RuleDeriveResult> {
         match statement {
-            Statement::Singular(_, ref terms) => terms.iter().for_each(|t| {
-                if !stack.contains(t) {
-                    stack.push(t.clone());
+            Statement::Identity(ref lterm, ref rterm) => {
+                // Substitution of identicals: given 'a = b', any atomic statement
+                // (or negation of one) on the branch that contains 'a' may be
+                // rewritten with 'b' in its place, and vice-versa.
+                //
+                // Like UQ, this rule only derives one statement at a time, and
+                // is reapplied for as long as it keeps finding something new.
+                // Since no new singular terms are ever introduced, there is only
+                // a finite number of statements that can be derived this way.
+                let on_branch = |st: &Statement| {
+                    self.tree
+                        .traverse_upwards_branches(&branch_id)
+                        .any(|(_, ancestor_branch)| {
+                            ancestor_branch
+                                .statements()
+                                .any(|(_, branch_node)| branch_node.statement == *st)
+                        })
+                };
+
+                let mut candidates = Vec::new();
+
+                for (_, ancestor_branch) in self.tree.traverse_upwards_branches(&branch_id) {
+                    for (_, branch_node) in ancestor_branch.statements() {
+                        if self.is_atomic_or_negation_of_atomic(&branch_node.statement) {
+                            candidates.push(branch_node.statement.clone());
+                        }
+                    }
                 }
-            }),
-            Statement::LogicalConjunction(ref lst, ref rst)
-            | Statement::LogicalDisjunction(ref lst, ref rst)
-            | Statement::LogicalConditional(ref lst, ref rst) => {
-                self.find_singular_terms_in_statement(&mut stack, &lst);
-                self.find_singular_terms_in_statement(&mut stack, &rst);
-            }
-            Statement::LogicalNegation(ref rst) => {
-                self.find_singular_terms_in_statement(&mut stack, &rst)
-            }
-            Statement::Existential(_, ref formula) | Statement::Universal(_, ref formula) => {
-                self.find_singular_terms_in_formula(&mut stack, &formula)
+
+                candidates
+                    .iter()
+                    .flat_map(|x| {
+                        vec![
+                            self.substitute_in_statement(&x, &lterm, &rterm),
+                            self.substitute_in_statement(&x, &rterm, &lterm),
+                        ]
+                    })
+                    .find(|x| match x {
+                        // Deriving 'a = a' is pointless
+                        Statement::Identity(ref l, ref r) if l == r => false,
+                        _ => !on_branch(&x),
+                    })
+                    .map(|x| RuleDeriveResult {
+                        statements: vec![x],
+                        whatdo: ApplyRuleWhatdo::AddToExistingBranches,
+                    })
             }
-            _ => {}
+            _ => panic!("attempt to apply wrong rule to statement"),
         }
     }
 
-    fn find_singular_terms_in_formula(&self, mut stack: &mut Vec<SingularTerm>, formula: &Formula) {
-        match formula {
-            Formula::Predicate(_, ref terms) => terms.iter().for_each(|t| match t {
-                Term::SingularTerm(ref singular_term) => {
-                    if !stack.contains(singular_term) {
-                        stack.push(singular_term.clone());
-                    }
+    fn is_atomic_or_negation_of_atomic(&self, statement: &Statement) -> bool {
+        match statement {
+            Statement::Simple(_) | Statement::Singular(_, _) | Statement::Identity(_, _) => true,
+            Statement::LogicalNegation(ref rst) => match **rst {
+                Statement::Simple(_) | Statement::Singular(_, _) | Statement::Identity(_, _) => {
+                    true
                 }
-                _ => {}
-            }),
-            Formula::Statement(ref st) => self.find_singular_terms_in_statement(&mut stack, &st),
-            Formula::Conjunction(ref lformula, ref rformula)
-            | Formula::Disjunction(ref lformula, ref rformula)
-            | Formula::Conditional(ref lformula, ref rformula) => {
-                self.find_singular_terms_in_formula(&mut stack, &lformula);
-                self.find_singular_terms_in_formula(&mut stack, &rformula);
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn substitute_in_statement(
+        &self,
+        statement: &Statement,
+        term: &SingularTerm,
+        replace_with: &SingularTerm,
+    ) -> Statement {
+        // Replaces every occurrence of 'term' in an atomic statement
+        // (or negation of one) with 'replace_with'
+        let substitute = |t: &SingularTerm| {
+            if t == term {
+                replace_with.clone()
+            } else {
+                t.clone()
+            }
+        };
+
+        match statement {
+            Statement::Singular(ref pred_letter, ref terms) => {
+                Statement::Singular(pred_letter.clone(), terms.iter().map(substitute).collect())
             }
-            Formula::Negation(ref rformula) => {
-                self.find_singular_terms_in_formula(&mut stack, &rformula);
+            Statement::Identity(ref lterm, ref rterm) => {
+                Statement::Identity(substitute(lterm), substitute(rterm))
+            }
+            Statement::LogicalNegation(ref rst) => Statement::LogicalNegation(Box::new(
+                self.substitute_in_statement(&rst, &term, &replace_with),
+            )),
+            _ => statement.clone(),
+        }
+    }
+
+    fn build_singular_term_stack_for_branch(&self, branch_id: &TreeId) -> Vec<SingularTerm> {
+        let mut stack = Vec::new();
+
+        for (_, ancestor_branch) in self.tree.traverse_upwards_branches(&branch_id) {
+            for (_, branch_node) in ancestor_branch.statements() {
+                SingularTermCollector(&mut stack).visit_statement(&branch_node.statement);
             }
         }
+
+        stack
     }
 
     fn instantiation_transform_into_statement(&self, formula: &Formula) -> Statement {
@@ -400,10 +523,24 @@ impl TruthTreeMethod {
                             Term::Variable(_) => {
                                 panic!("variable at root of instantiated quantified statement")
                             }
+                            // TruthTreeMethod::new panics on these
+                            Term::Function(_, _) => unreachable!(),
                         })
                         .collect(),
                 )
             }
+            Formula::Identity(ref lterm, ref rterm) => {
+                // Same as above
+                let into_singular_term = |x: &Term| match x {
+                    Term::SingularTerm(t) => t.clone(),
+                    Term::Variable(_) => {
+                        panic!("variable at root of instantiated quantified statement")
+                    }
+                    Term::Function(_, _) => unreachable!(),
+                };
+
+                Statement::Identity(into_singular_term(lterm), into_singular_term(rterm))
+            }
             Formula::Conjunction(ref lformula, ref rformula) => Statement::LogicalConjunction(
                 Box::new(self.instantiation_transform_into_statement(&*lformula.clone())),
                 Box::new(self.instantiation_transform_into_statement(&*rformula.clone())),
@@ -419,143 +556,11 @@ impl TruthTreeMethod {
                 Box::new(self.instantiation_transform_into_statement(&*lformula.clone())),
                 Box::new(self.instantiation_transform_into_statement(&*rformula.clone())),
             ),
-            Formula::Statement(ref st) => *st.clone(), // An existential or universal statement
-        }
-    }
-
-    fn instantiation_replace_in_formula(
-        &self,
-        formula: &Formula,
-        var: &Variable,
-        replace_with: &SingularTerm,
-    ) -> Formula {
-        // Replaces all occurrences of 'var' with 'replace_with' but leaves everything
-        // else as-is
-        match formula {
-            Formula::Predicate(ref pred_letter, ref terms) => {
-                let terms = terms
-                    .iter()
-                    .map(|x| match x {
-                        Term::Variable(ref v) => {
-                            if v == var {
-                                Term::SingularTerm(replace_with.clone())
-                            } else {
-                                Term::Variable(v.clone())
-                            }
-                        }
-                        t @ Term::SingularTerm(_) => t.clone(),
-                    })
-                    .collect();
-
-                Formula::Predicate(pred_letter.clone(), terms)
-            }
-            Formula::Conjunction(ref lformula, ref rformula) => Formula::Conjunction(
-                Box::new(self.instantiation_replace_in_formula(
-                    &*lformula.clone(),
-                    &var,
-                    &replace_with,
-                )),
-                Box::new(self.instantiation_replace_in_formula(
-                    &*rformula.clone(),
-                    &var,
-                    &replace_with,
-                )),
-            ),
-            Formula::Negation(ref rformula) => Formula::Negation(Box::new(
-                self.instantiation_replace_in_formula(&*rformula.clone(), &var, &replace_with),
-            )),
-            Formula::Disjunction(ref lformula, ref rformula) => Formula::Disjunction(
-                Box::new(self.instantiation_replace_in_formula(
-                    &*lformula.clone(),
-                    &var,
-                    &replace_with,
-                )),
-                Box::new(self.instantiation_replace_in_formula(
-                    &*rformula.clone(),
-                    &var,
-                    &replace_with,
-                )),
-            ),
-            Formula::Conditional(ref lformula, ref rformula) => Formula::Conditional(
-                Box::new(self.instantiation_replace_in_formula(
-                    &*lformula.clone(),
-                    &var,
-                    &replace_with,
-                )),
-                Box::new(self.instantiation_replace_in_formula(
-                    &*rformula.clone(),
-                    &var,
-                    &replace_with,
-                )),
+            Formula::Biconditional(ref lformula, ref rformula) => Statement::LogicalBiconditional(
+                Box::new(self.instantiation_transform_into_statement(&*lformula.clone())),
+                Box::new(self.instantiation_transform_into_statement(&*rformula.clone())),
             ),
-            Formula::Statement(ref statement) => match **statement {
-                Statement::Simple(_) | Statement::Singular(_, _) => {
-                    Formula::Statement(Box::new(*statement.clone()))
-                }
-                Statement::LogicalConjunction(ref lst, ref rst) => Formula::Conjunction(
-                    Box::new(self.instantiation_replace_in_formula(
-                        &Formula::Statement(lst.clone()),
-                        &var,
-                        &replace_with,
-                    )),
-                    Box::new(self.instantiation_replace_in_formula(
-                        &Formula::Statement(rst.clone()),
-                        &var,
-                        &replace_with,
-                    )),
-                ),
-                Statement::LogicalNegation(ref rst) => {
-                    Formula::Negation(Box::new(self.instantiation_replace_in_formula(
-                        &Formula::Statement(rst.clone()),
-                        &var,
-                        &replace_with,
-                    )))
-                }
-                Statement::LogicalDisjunction(ref lst, ref rst) => Formula::Disjunction(
-                    Box::new(self.instantiation_replace_in_formula(
-                        &Formula::Statement(lst.clone()),
-                        &var,
-                        &replace_with,
-                    )),
-                    Box::new(self.instantiation_replace_in_formula(
-                        &Formula::Statement(rst.clone()),
-                        &var,
-                        &replace_with,
-                    )),
-                ),
-                Statement::LogicalConditional(ref lst, ref rst) => Formula::Conditional(
-                    Box::new(self.instantiation_replace_in_formula(
-                        &Formula::Statement(lst.clone()),
-                        &var,
-                        &replace_with,
-                    )),
-                    Box::new(self.instantiation_replace_in_formula(
-                        &Formula::Statement(rst.clone()),
-                        &var,
-                        &replace_with,
-                    )),
-                ),
-                Statement::Existential(ref var_pls_dont_shadow, ref formula) => {
-                    Formula::Statement(Box::new(Statement::Existential(
-                        var_pls_dont_shadow.clone(),
-                        Box::new(self.instantiation_replace_in_formula(
-                            &formula,
-                            &var,
-                            &replace_with,
-                        )),
-                    )))
-                }
-                Statement::Universal(ref var_pls_dont_shadow, ref formula) => {
-                    Formula::Statement(Box::new(Statement::Universal(
-                        var_pls_dont_shadow.clone(),
-                        Box::new(self.instantiation_replace_in_formula(
-                            &formula,
-                            &var,
-                            &replace_with,
-                        )),
-                    )))
-                }
-            },
+            Formula::Statement(ref st) => *st.clone(), // Has no free variables, so is left as is
         }
     }
 
@@ -577,41 +582,67 @@ impl TruthTreeMethod {
         // also HAS to be a statement.
         match statement {
             Statement::Existential(ref var, ref formula)
-            | Statement::Universal(ref var, ref formula) => self
-                .instantiation_transform_into_statement(&self.instantiation_replace_in_formula(
-                    &formula,
-                    &var,
-                    &replace_with,
-                )),
+            | Statement::Universal(ref var, ref formula) => {
+                let formula = VariableReplacer { var, replace_with }.fold_formula(*formula.clone());
+
+                self.instantiation_transform_into_statement(&formula)
+            }
             _ => panic!(
                 "called instantiated_quantified_statement\
                  with non-quantified statement"
             ),
         }
     }
+}
 
-    fn first_unused_in_singular_term_stack(&self, stack: &Vec<SingularTerm>) -> SingularTerm {
-        // Ideally, this would be encapsulated by the parser, so we wouldn't
-        // have to deal with the plain text grammar
-        //
-        // First iterates from 'a' to 'w' checking if any
-        // of these singular terms are available
-        // Returns the first one
-        // If none are, it checks if 'a1' is available (where 1 is a
-        // subscript), or 'b1', or 'c1', or 'd1', etc.
-        // If it is, returns that
-        // If it isn't, it checks if 'a2', 'b2', 'c2', etc. is available
-        // etc. etc. etc.
-        for subscript in once(Subscript(None)).chain((1..).map(|x| Subscript(Some(x)))) {
-            for c in 'a' as u8..='w' as u8 {
-                let singular_term = SingularTerm(c as char, subscript.clone());
-                if !stack.iter().any(|x| x == &singular_term) {
-                    return singular_term;
-                }
+pub(crate) fn first_unused_in_singular_term_stack(stack: &[SingularTerm]) -> SingularTerm {
+    // Ideally, this would be encapsulated by the parser, so we wouldn't
+    // have to deal with the plain text grammar
+    //
+    // First iterates from 'a' to 'w' checking if any
+    // of these singular terms are available
+    // Returns the first one
+    // If none are, it checks if 'a1' is available (where 1 is a
+    // subscript), or 'b1', or 'c1', or 'd1', etc.
+    // If it is, returns that
+    // If it isn't, it checks if 'a2', 'b2', 'c2', etc. is available
+    // etc. etc. etc.
+    for subscript in once(Subscript(None)).chain((1..).map(|x| Subscript(Some(x)))) {
+        for c in 'a' as u8..='w' as u8 {
+            let singular_term = SingularTerm(c as char, subscript.clone());
+            if !stack.iter().any(|x| x == &singular_term) {
+                return singular_term;
             }
         }
+    }
+
+    unreachable!()
+}
+
+// Collects every singular term, in the order they appear in, without repeats
+struct SingularTermCollector<'a>(&'a mut Vec<SingularTerm>);
+
+impl<'a> Visit for SingularTermCollector<'a> {
+    fn visit_singular_term(&mut self, singular_term: &SingularTerm) {
+        if !self.0.contains(singular_term) {
+            self.0.push(singular_term.clone());
+        }
+    }
+}
 
-        unreachable!()
+// Replaces all occurrences of 'var' with 'replace_with' but leaves everything
+// else as-is
+struct VariableReplacer<'a> {
+    var: &'a Variable,
+    replace_with: &'a SingularTerm,
+}
+
+impl<'a> Fold for VariableReplacer<'a> {
+    fn fold_term(&mut self, term: Term) -> Term {
+        match term {
+            Term::Variable(ref v) if v == self.var => Term::SingularTerm(self.replace_with.clone()),
+            term => term,
+        }
     }
 }
 
@@ -775,6 +806,36 @@ mod tests {
             Some((Rule::Disjunction, false))
         );
 
+        assert_eq!(
+            truth_tree_method.matches_some_rule(&Statement::LogicalBiconditional(
+                Box::new(Statement::Simple(SimpleStatementLetter(
+                    'C',
+                    Subscript(None)
+                ))),
+                Box::new(Statement::Simple(SimpleStatementLetter(
+                    'D',
+                    Subscript(None)
+                )))
+            )),
+            Some((Rule::Biconditional, false))
+        );
+
+        assert_eq!(
+            truth_tree_method.matches_some_rule(&Statement::LogicalNegation(Box::new(
+                Statement::LogicalBiconditional(
+                    Box::new(Statement::Simple(SimpleStatementLetter(
+                        'C',
+                        Subscript(None)
+                    ))),
+                    Box::new(Statement::Simple(SimpleStatementLetter(
+                        'D',
+                        Subscript(None)
+                    )))
+                )
+            ))),
+            Some((Rule::NegationOfBiconditional, false))
+        );
+
         assert_eq!(
             truth_tree_method.matches_some_rule(&Statement::Simple(SimpleStatementLetter(
                 'C',
@@ -782,6 +843,16 @@ mod tests {
             ))),
             None
         );
+
+        // Identity statements are atomic, even though they also have a rule
+        // of their own (see TruthTreeMethod::compute)
+        assert_eq!(
+            truth_tree_method.matches_some_rule(&Statement::Identity(
+                SingularTerm('a', Subscript(None)),
+                SingularTerm('b', Subscript(None))
+            )),
+            None
+        );
     }
 
     #[test]
@@ -818,6 +889,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
 
         let rule_derive_result = truth_tree_method
@@ -848,6 +920,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -882,6 +955,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -918,6 +992,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
 
         // If singular terms already appear on the branch, instantiate to each one of them at a time
@@ -961,6 +1036,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -988,6 +1064,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -1026,6 +1103,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -1067,6 +1145,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -1111,6 +1190,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => {}
             ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -1152,6 +1232,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => assert!(false),
             ApplyRuleWhatdo::AsNewBranches => {}
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -1196,6 +1277,7 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => assert!(false),
             ApplyRuleWhatdo::AsNewBranches => {}
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
@@ -1234,9 +1316,144 @@ mod tests {
         match rule_derive_result.whatdo {
             ApplyRuleWhatdo::AddToExistingBranches => assert!(false),
             ApplyRuleWhatdo::AsNewBranches => {}
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
         }
     }
 
+    #[test]
+    fn apply_biconditional_rule() {
+        let truth_tree_method = TruthTreeMethod::new(&vec![Statement::Simple(
+            SimpleStatementLetter('A', Subscript(None)),
+        )]);
+
+        let a = Statement::Simple(SimpleStatementLetter('A', Subscript(None)));
+        let b = Statement::Simple(SimpleStatementLetter('B', Subscript(None)));
+
+        let rule_derive_result = truth_tree_method
+            .apply_rule(
+                Rule::Biconditional,
+                &Statement::LogicalBiconditional(Box::new(a.clone()), Box::new(b.clone())),
+                &truth_tree_method.tree.main_trunk_id(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            rule_derive_result.statements,
+            vec![
+                a.clone(),
+                b.clone(),
+                Statement::LogicalNegation(Box::new(a)),
+                Statement::LogicalNegation(Box::new(b))
+            ]
+        );
+        match rule_derive_result.whatdo {
+            ApplyRuleWhatdo::AddToExistingBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(n) => assert_eq!(n, 2),
+        }
+    }
+
+    #[test]
+    fn apply_negation_of_biconditional_rule() {
+        let truth_tree_method = TruthTreeMethod::new(&vec![Statement::Simple(
+            SimpleStatementLetter('A', Subscript(None)),
+        )]);
+
+        let a = Statement::Simple(SimpleStatementLetter('A', Subscript(None)));
+        let b = Statement::Simple(SimpleStatementLetter('B', Subscript(None)));
+
+        let rule_derive_result = truth_tree_method
+            .apply_rule(
+                Rule::NegationOfBiconditional,
+                &Statement::LogicalNegation(Box::new(Statement::LogicalBiconditional(
+                    Box::new(a.clone()),
+                    Box::new(b.clone()),
+                ))),
+                &truth_tree_method.tree.main_trunk_id(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            rule_derive_result.statements,
+            vec![
+                a.clone(),
+                Statement::LogicalNegation(Box::new(b.clone())),
+                Statement::LogicalNegation(Box::new(a)),
+                b
+            ]
+        );
+        match rule_derive_result.whatdo {
+            ApplyRuleWhatdo::AddToExistingBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(n) => assert_eq!(n, 2),
+        }
+    }
+
+    #[test]
+    fn apply_identity_rule() {
+        let truth_tree_method = TruthTreeMethod::new(&vec![
+            Statement::Identity(
+                SingularTerm('a', Subscript(None)),
+                SingularTerm('b', Subscript(None)),
+            ),
+            Statement::LogicalNegation(Box::new(Statement::Singular(
+                PredicateLetter('L', Subscript(None), Degree(2)),
+                vec![
+                    SingularTerm('a', Subscript(None)),
+                    SingularTerm('c', Subscript(None)),
+                ],
+            ))),
+        ]);
+
+        let rule_derive_result = truth_tree_method
+            .apply_rule(
+                Rule::Identity,
+                &Statement::Identity(
+                    SingularTerm('a', Subscript(None)),
+                    SingularTerm('b', Subscript(None)),
+                ),
+                &truth_tree_method.tree.main_trunk_id(),
+            )
+            .unwrap();
+
+        assert_eq!(rule_derive_result.statements.len(), 1);
+        assert_eq!(
+            rule_derive_result.statements.first().unwrap(),
+            &Statement::LogicalNegation(Box::new(Statement::Singular(
+                PredicateLetter('L', Subscript(None), Degree(2)),
+                vec![
+                    SingularTerm('b', Subscript(None)),
+                    SingularTerm('c', Subscript(None))
+                ]
+            )))
+        );
+        match rule_derive_result.whatdo {
+            ApplyRuleWhatdo::AddToExistingBranches => {}
+            ApplyRuleWhatdo::AsNewBranches => assert!(false),
+            ApplyRuleWhatdo::AsNewBranchesInGroupsOf(_) => assert!(false),
+        }
+
+        // Nothing left to substitute
+        let truth_tree_method = TruthTreeMethod::new(&vec![
+            Statement::Identity(
+                SingularTerm('a', Subscript(None)),
+                SingularTerm('b', Subscript(None)),
+            ),
+            Statement::Simple(SimpleStatementLetter('A', Subscript(None))),
+        ]);
+
+        assert!(truth_tree_method
+            .apply_rule(
+                Rule::Identity,
+                &Statement::Identity(
+                    SingularTerm('a', Subscript(None)),
+                    SingularTerm('b', Subscript(None)),
+                ),
+                &truth_tree_method.tree.main_trunk_id(),
+            )
+            .is_none());
+    }
+
     #[test]
     fn build_singular_term_stack_for_branch() {
         let mut truth_tree_method = TruthTreeMethod::new(&vec![Statement::Existential(
@@ -0,0 +1,838 @@
+use super::truth_tree::{TreeId, TruthTree};
+use crate::parser::{
+    Formula, PredicateLetter, SimpleStatementLetter, SingularTerm, Statement, Term, Variable,
+};
+use std::collections::{HashMap, HashSet};
+use std::error::Error;
+use std::fmt;
+
+/// An object of the domain of an [Interpretation](struct.Interpretation.html).
+///
+/// **Serialization of this struct requires the feature `serde_support` to be enabled.**
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
+pub struct Object(pub usize);
+
+/// An interpretation of the statements of a truth tree: a domain of objects,
+/// the object each singular term denotes, the extension of each predicate letter
+/// (the tuples of objects it is true of), and the truth value of each simple
+/// statement letter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interpretation {
+    domain: Vec<Object>,
+    denotations: HashMap<SingularTerm, Object>,
+    extensions: HashMap<PredicateLetter, HashSet<Vec<Object>>>,
+    truth_values: HashMap<SimpleStatementLetter, bool>,
+}
+
+/// An error that may occur when building an [Interpretation](struct.Interpretation.html),
+/// or when evaluating a statement or formula on it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvaluationError {
+    /// An interpretation was given an empty domain.
+    EmptyDomain,
+    /// An object isn't in the domain of the interpretation.
+    ObjectNotInDomain(Object),
+    /// A tuple of the extension of a predicate letter doesn't have as many objects
+    /// as the degree of the predicate letter.
+    ArityMismatch {
+        predicate_letter: PredicateLetter,
+        expected: u64,
+        found: usize,
+    },
+    /// A simple statement letter isn't given a truth value.
+    UninterpretedSimpleStatementLetter(SimpleStatementLetter),
+    /// A predicate letter isn't given an extension.
+    UninterpretedPredicateLetter(PredicateLetter),
+    /// A singular term doesn't denote any object.
+    UninterpretedSingularTerm(SingularTerm),
+    /// A variable that isn't bound by any quantifier isn't assigned any object
+    /// (see [Interpretation::evaluate_formula](struct.Interpretation.html#method.evaluate_formula)).
+    UnassignedVariable(Variable),
+    /// A function term, which interpretations don't give any meaning to
+    /// (see [Term](enum.Term.html)).
+    FunctionTermNotAllowed(Term),
+}
+
+impl Interpretation {
+    /// Creates an interpretation with a domain of `size` objects, `Object(0)` to
+    /// `Object(size - 1)`, that doesn't interpret any symbol yet.
+    ///
+    /// Fails if `size` is 0, since the domain can't be empty.
+    pub fn new(size: usize) -> Result<Self, EvaluationError> {
+        if size == 0 {
+            return Err(EvaluationError::EmptyDomain);
+        }
+
+        Ok(Interpretation {
+            domain: (0..size).map(Object).collect(),
+            denotations: HashMap::new(),
+            extensions: HashMap::new(),
+            truth_values: HashMap::new(),
+        })
+    }
+
+    /// Sets the object that `singular_term` denotes.
+    ///
+    /// Fails if `object` isn't in the domain.
+    pub fn with_denotation(
+        mut self,
+        singular_term: SingularTerm,
+        object: Object,
+    ) -> Result<Self, EvaluationError> {
+        self.check_in_domain(object)?;
+
+        self.denotations.insert(singular_term, object);
+        Ok(self)
+    }
+
+    /// Sets the extension of `predicate_letter`, i.e. the tuples of objects it is true of.
+    ///
+    /// Fails if some tuple doesn't have as many objects as the degree of `predicate_letter`,
+    /// or if some object isn't in the domain.
+    pub fn with_extension<I>(
+        mut self,
+        predicate_letter: PredicateLetter,
+        extension: I,
+    ) -> Result<Self, EvaluationError>
+    where
+        I: IntoIterator<Item = Vec<Object>>,
+    {
+        let extension = extension.into_iter().collect::<HashSet<_>>();
+
+        for objects in &extension {
+            if objects.len() as u64 != (predicate_letter.2).0 {
+                return Err(EvaluationError::ArityMismatch {
+                    expected: (predicate_letter.2).0,
+                    found: objects.len(),
+                    predicate_letter,
+                });
+            }
+
+            for object in objects {
+                self.check_in_domain(*object)?;
+            }
+        }
+
+        self.extensions.insert(predicate_letter, extension);
+        Ok(self)
+    }
+
+    /// Sets the truth value of `letter`.
+    pub fn with_truth_value(mut self, letter: SimpleStatementLetter, truth_value: bool) -> Self {
+        self.truth_values.insert(letter, truth_value);
+        self
+    }
+
+    /// Returns the domain of discourse. It is never empty.
+    pub fn domain(&self) -> &[Object] {
+        &self.domain
+    }
+
+    /// Returns the object each singular term denotes.
+    pub fn denotations(&self) -> &HashMap<SingularTerm, Object> {
+        &self.denotations
+    }
+
+    /// Returns the extension of each predicate letter.
+    pub fn extensions(&self) -> &HashMap<PredicateLetter, HashSet<Vec<Object>>> {
+        &self.extensions
+    }
+
+    /// Returns the truth value of each simple statement letter.
+    pub fn truth_values(&self) -> &HashMap<SimpleStatementLetter, bool> {
+        &self.truth_values
+    }
+
+    /// Returns the object some singular term denotes, if it is interpreted.
+    pub fn denotation(&self, singular_term: &SingularTerm) -> Option<Object> {
+        self.denotations.get(singular_term).cloned()
+    }
+
+    /// Returns the extension of some predicate letter, if it is interpreted.
+    pub fn extension(&self, predicate_letter: &PredicateLetter) -> Option<&HashSet<Vec<Object>>> {
+        self.extensions.get(predicate_letter)
+    }
+
+    /// Returns the truth value of some simple statement letter, if it is interpreted.
+    pub fn truth_value(&self, letter: &SimpleStatementLetter) -> Option<bool> {
+        self.truth_values.get(letter).cloned()
+    }
+
+    pub(crate) fn from_branch(tree: &TruthTree, branch_id: &TreeId) -> Option<Interpretation> {
+        // Only a completed open branch describes an interpretation
+        if tree.branch_from_id(&branch_id).is_closed() || !tree.branch_is_last_child(&branch_id) {
+            return None;
+        }
+
+        let statements = tree
+            .traverse_upwards_branches(&branch_id)
+            .flat_map(|(_, branch)| branch.statements().map(|(_, x)| x.statement.clone()))
+            .collect::<Vec<_>>();
+
+        let mut symbols = Symbols::default();
+
+        for statement in &statements {
+            symbols.collect_from_statement(&statement);
+        }
+
+        // Singular terms that are identical on the branch denote the same object,
+        // so the domain is made of one object per class of identical terms
+        let mut classes: Vec<Vec<SingularTerm>> = symbols
+            .singular_terms
+            .iter()
+            .map(|x| vec![x.clone()])
+            .collect();
+
+        for statement in &statements {
+            if let Statement::Identity(ref lterm, ref rterm) = statement {
+                let lclass = classes.iter().position(|x| x.contains(lterm)).unwrap();
+                let rclass = classes.iter().position(|x| x.contains(rterm)).unwrap();
+
+                if lclass != rclass {
+                    let merged = classes.remove(rclass.max(lclass));
+                    classes[rclass.min(lclass)].extend(merged);
+                }
+            }
+        }
+
+        let mut denotations = HashMap::new();
+
+        for (i, class) in classes.iter().enumerate() {
+            for singular_term in class {
+                denotations.insert(singular_term.clone(), Object(i));
+            }
+        }
+
+        // The domain can't be empty, even if no singular term was ever introduced
+        let domain = (0..classes.len().max(1)).map(Object).collect();
+
+        let mut extensions = symbols
+            .predicate_letters
+            .iter()
+            .map(|x| (x.clone(), HashSet::new()))
+            .collect::<HashMap<_, _>>();
+
+        let mut truth_values = symbols
+            .simple_statement_letters
+            .iter()
+            .map(|x| (x.clone(), false))
+            .collect::<HashMap<_, _>>();
+
+        // Everything an atomic statement on the branch asserts is true, and everything
+        // else is false
+        for statement in &statements {
+            match statement {
+                Statement::Simple(ref letter) => {
+                    truth_values.insert(letter.clone(), true);
+                }
+                Statement::Singular(ref pred_letter, ref terms) => {
+                    extensions
+                        .get_mut(pred_letter)
+                        .unwrap()
+                        .insert(terms.iter().map(|x| denotations[x]).collect());
+                }
+                _ => {}
+            }
+        }
+
+        let interpretation = Interpretation {
+            domain,
+            denotations,
+            extensions,
+            truth_values,
+        };
+
+        // If the branch really is complete, this always holds, but it is cheap to make sure
+        if statements.iter().all(|x| interpretation.satisfies(&x)) {
+            Some(interpretation)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn satisfies(&self, statement: &Statement) -> bool {
+        self.evaluate(statement) == Ok(true)
+    }
+
+    /// Returns the truth value of some statement on this interpretation. Quantified
+    /// statements are evaluated by assigning each object of the domain, in turn, to the
+    /// variable they bind.
+    ///
+    /// Fails if some symbol of the statement isn't interpreted, or if it contains some
+    /// function term. Every part of the statement is evaluated (e.g. both sides of a
+    /// conjunction, even if the left-hand side is false), so that such errors are found
+    /// no matter what the truth values of the other parts are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use logic_rs::{parse_input, InputKind, Interpretation, Object};
+    /// # use logic_rs::{Degree, PredicateLetter, Subscript};
+    /// # use logic_rs::builder::term;
+    /// let statement = match parse_input("∃xL²ax") {
+    ///     Ok(InputKind::Statement(st)) => st.statement().clone(),
+    ///     _ => unreachable!(),
+    /// };
+    ///
+    /// let interpretation = Interpretation::new(2)?
+    ///     .with_denotation(term('a'), Object(0))?
+    ///     .with_extension(
+    ///         PredicateLetter('L', Subscript(None), Degree(2)),
+    ///         vec![vec![Object(0), Object(1)]],
+    ///     )?;
+    ///
+    /// assert_eq!(interpretation.evaluate(&statement), Ok(true));
+    /// # Ok::<(), logic_rs::EvaluationError>(())
+    /// ```
+    pub fn evaluate(&self, statement: &Statement) -> Result<bool, EvaluationError> {
+        self.statement_value(statement, &mut vec![])
+    }
+
+    /// Returns the truth value of some formula on this interpretation, given the object
+    /// that each variable that isn't bound by any quantifier of the formula is assigned.
+    ///
+    /// Fails the same way as [evaluate](#method.evaluate), or if some variable that isn't
+    /// bound by any quantifier of the formula isn't assigned any object, or if some object
+    /// of `assignment` isn't in the domain.
+    pub fn evaluate_formula(
+        &self,
+        formula: &Formula,
+        assignment: &HashMap<Variable, Object>,
+    ) -> Result<bool, EvaluationError> {
+        let mut assignment = assignment
+            .iter()
+            .map(|(var, object)| (var.clone(), *object))
+            .collect::<Vec<_>>();
+
+        for (_, object) in &assignment {
+            self.check_in_domain(*object)?;
+        }
+
+        self.formula_value(formula, &mut assignment)
+    }
+
+    fn statement_value(
+        &self,
+        statement: &Statement,
+        assignment: &mut Vec<(Variable, Object)>,
+    ) -> Result<bool, EvaluationError> {
+        match statement {
+            Statement::Simple(ref letter) => self
+                .truth_value(letter)
+                .ok_or_else(|| EvaluationError::UninterpretedSimpleStatementLetter(letter.clone())),
+            Statement::Singular(ref pred_letter, ref terms) => {
+                let extension = self.extension_or_err(pred_letter)?;
+                let objects = terms
+                    .iter()
+                    .map(|x| self.denotation_or_err(x))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(extension.contains(&objects))
+            }
+            Statement::Identity(ref lterm, ref rterm) => {
+                Ok(self.denotation_or_err(lterm)? == self.denotation_or_err(rterm)?)
+            }
+            Statement::LogicalConjunction(ref lst, ref rst) => {
+                let (lvalue, rvalue) = (
+                    self.statement_value(&lst, assignment)?,
+                    self.statement_value(&rst, assignment)?,
+                );
+
+                Ok(lvalue && rvalue)
+            }
+            Statement::LogicalNegation(ref rst) => Ok(!self.statement_value(&rst, assignment)?),
+            Statement::LogicalDisjunction(ref lst, ref rst) => {
+                let (lvalue, rvalue) = (
+                    self.statement_value(&lst, assignment)?,
+                    self.statement_value(&rst, assignment)?,
+                );
+
+                Ok(lvalue || rvalue)
+            }
+            Statement::LogicalConditional(ref lst, ref rst) => {
+                let (lvalue, rvalue) = (
+                    self.statement_value(&lst, assignment)?,
+                    self.statement_value(&rst, assignment)?,
+                );
+
+                Ok(!lvalue || rvalue)
+            }
+            Statement::LogicalBiconditional(ref lst, ref rst) => {
+                let (lvalue, rvalue) = (
+                    self.statement_value(&lst, assignment)?,
+                    self.statement_value(&rst, assignment)?,
+                );
+
+                Ok(lvalue == rvalue)
+            }
+            Statement::Existential(ref var, ref formula) => {
+                // Since every part of the formula is evaluated, any error is found
+                // with the first object already, so it is fine to stop early
+                for object in &self.domain {
+                    assignment.push((var.clone(), *object));
+                    let value = self.formula_value(&formula, assignment);
+                    assignment.pop();
+
+                    if value? {
+                        return Ok(true);
+                    }
+                }
+
+                Ok(false)
+            }
+            Statement::Universal(ref var, ref formula) => {
+                // Same as above
+                for object in &self.domain {
+                    assignment.push((var.clone(), *object));
+                    let value = self.formula_value(&formula, assignment);
+                    assignment.pop();
+
+                    if !value? {
+                        return Ok(false);
+                    }
+                }
+
+                Ok(true)
+            }
+        }
+    }
+
+    fn formula_value(
+        &self,
+        formula: &Formula,
+        assignment: &mut Vec<(Variable, Object)>,
+    ) -> Result<bool, EvaluationError> {
+        match formula {
+            Formula::Statement(ref st) => self.statement_value(&st, assignment),
+            Formula::Predicate(ref pred_letter, ref terms) => {
+                let extension = self.extension_or_err(pred_letter)?;
+                let objects = terms
+                    .iter()
+                    .map(|x| self.term_value(x, assignment))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                Ok(extension.contains(&objects))
+            }
+            Formula::Identity(ref lterm, ref rterm) => {
+                Ok(self.term_value(lterm, assignment)? == self.term_value(rterm, assignment)?)
+            }
+            Formula::Conjunction(ref lformula, ref rformula) => {
+                let (lvalue, rvalue) = (
+                    self.formula_value(&lformula, assignment)?,
+                    self.formula_value(&rformula, assignment)?,
+                );
+
+                Ok(lvalue && rvalue)
+            }
+            Formula::Negation(ref rformula) => Ok(!self.formula_value(&rformula, assignment)?),
+            Formula::Disjunction(ref lformula, ref rformula) => {
+                let (lvalue, rvalue) = (
+                    self.formula_value(&lformula, assignment)?,
+                    self.formula_value(&rformula, assignment)?,
+                );
+
+                Ok(lvalue || rvalue)
+            }
+            Formula::Conditional(ref lformula, ref rformula) => {
+                let (lvalue, rvalue) = (
+                    self.formula_value(&lformula, assignment)?,
+                    self.formula_value(&rformula, assignment)?,
+                );
+
+                Ok(!lvalue || rvalue)
+            }
+            Formula::Biconditional(ref lformula, ref rformula) => {
+                let (lvalue, rvalue) = (
+                    self.formula_value(&lformula, assignment)?,
+                    self.formula_value(&rformula, assignment)?,
+                );
+
+                Ok(lvalue == rvalue)
+            }
+        }
+    }
+
+    fn term_value(
+        &self,
+        term: &Term,
+        assignment: &[(Variable, Object)],
+    ) -> Result<Object, EvaluationError> {
+        match term {
+            Term::SingularTerm(ref singular_term) => self.denotation_or_err(singular_term),
+            // The innermost quantifier binding the variable is the last one assigned
+            Term::Variable(ref var) => assignment
+                .iter()
+                .rev()
+                .find(|(x, _)| x == var)
+                .map(|(_, object)| *object)
+                .ok_or_else(|| EvaluationError::UnassignedVariable(var.clone())),
+            // Interpretations don't give function symbols any meaning
+            Term::Function(_, _) => Err(EvaluationError::FunctionTermNotAllowed(term.clone())),
+        }
+    }
+
+    fn check_in_domain(&self, object: Object) -> Result<(), EvaluationError> {
+        if self.domain.contains(&object) {
+            Ok(())
+        } else {
+            Err(EvaluationError::ObjectNotInDomain(object))
+        }
+    }
+
+    fn denotation_or_err(&self, singular_term: &SingularTerm) -> Result<Object, EvaluationError> {
+        self.denotation(singular_term)
+            .ok_or_else(|| EvaluationError::UninterpretedSingularTerm(singular_term.clone()))
+    }
+
+    fn extension_or_err(
+        &self,
+        pred_letter: &PredicateLetter,
+    ) -> Result<&HashSet<Vec<Object>>, EvaluationError> {
+        self.extension(pred_letter)
+            .ok_or_else(|| EvaluationError::UninterpretedPredicateLetter(pred_letter.clone()))
+    }
+}
+
+impl fmt::Display for EvaluationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvaluationError::EmptyDomain => write!(f, "the domain can't be empty"),
+            EvaluationError::ObjectNotInDomain(ref object) => {
+                write!(f, "object {} isn't in the domain", object.0)
+            }
+            EvaluationError::ArityMismatch {
+                ref predicate_letter,
+                expected,
+                found,
+            } => write!(
+                f,
+                "predicate letter {} is true of tuples of {} objects, not {}",
+                predicate_letter, expected, found
+            ),
+            EvaluationError::UninterpretedSimpleStatementLetter(ref letter) => {
+                write!(f, "simple statement letter {} has no truth value", letter)
+            }
+            EvaluationError::UninterpretedPredicateLetter(ref pred_letter) => {
+                write!(f, "predicate letter {} has no extension", pred_letter)
+            }
+            EvaluationError::UninterpretedSingularTerm(ref singular_term) => {
+                write!(
+                    f,
+                    "singular term {} doesn't denote any object",
+                    singular_term
+                )
+            }
+            EvaluationError::UnassignedVariable(ref var) => {
+                write!(f, "variable {} isn't assigned any object", var)
+            }
+            EvaluationError::FunctionTermNotAllowed(ref term) => {
+                write!(f, "function term {} can't be evaluated", term)
+            }
+        }
+    }
+}
+
+impl Error for EvaluationError {}
+
+// Every symbol that appears in some set of statements, in order of appearance
+#[derive(Default)]
+struct Symbols {
+    singular_terms: Vec<SingularTerm>,
+    predicate_letters: Vec<PredicateLetter>,
+    simple_statement_letters: Vec<SimpleStatementLetter>,
+}
+
+impl Symbols {
+    fn add_singular_term(&mut self, singular_term: &SingularTerm) {
+        if !self.singular_terms.contains(singular_term) {
+            self.singular_terms.push(singular_term.clone());
+        }
+    }
+
+    fn add_predicate_letter(&mut self, pred_letter: &PredicateLetter) {
+        if !self.predicate_letters.contains(pred_letter) {
+            self.predicate_letters.push(pred_letter.clone());
+        }
+    }
+
+    fn add_term(&mut self, term: &Term) {
+        match term {
+            Term::SingularTerm(ref singular_term) => self.add_singular_term(singular_term),
+            Term::Variable(_) => {}
+            Term::Function(_, ref terms) => terms.iter().for_each(|x| self.add_term(x)),
+        }
+    }
+
+    fn collect_from_statement(&mut self, statement: &Statement) {
+        match statement {
+            Statement::Simple(ref letter) => {
+                if !self.simple_statement_letters.contains(letter) {
+                    self.simple_statement_letters.push(letter.clone());
+                }
+            }
+            Statement::Singular(ref pred_letter, ref terms) => {
+                self.add_predicate_letter(pred_letter);
+                terms.iter().for_each(|x| self.add_singular_term(x));
+            }
+            Statement::Identity(ref lterm, ref rterm) => {
+                self.add_singular_term(lterm);
+                self.add_singular_term(rterm);
+            }
+            Statement::LogicalConjunction(ref lst, ref rst)
+            | Statement::LogicalDisjunction(ref lst, ref rst)
+            | Statement::LogicalConditional(ref lst, ref rst)
+            | Statement::LogicalBiconditional(ref lst, ref rst) => {
+                self.collect_from_statement(&lst);
+                self.collect_from_statement(&rst);
+            }
+            Statement::LogicalNegation(ref rst) => self.collect_from_statement(&rst),
+            Statement::Existential(_, ref formula) | Statement::Universal(_, ref formula) => {
+                self.collect_from_formula(&formula)
+            }
+        }
+    }
+
+    fn collect_from_formula(&mut self, formula: &Formula) {
+        match formula {
+            Formula::Statement(ref st) => self.collect_from_statement(&st),
+            Formula::Predicate(ref pred_letter, ref terms) => {
+                self.add_predicate_letter(pred_letter);
+                terms.iter().for_each(|x| self.add_term(x));
+            }
+            Formula::Identity(ref lterm, ref rterm) => {
+                self.add_term(lterm);
+                self.add_term(rterm);
+            }
+            Formula::Conjunction(ref lformula, ref rformula)
+            | Formula::Disjunction(ref lformula, ref rformula)
+            | Formula::Conditional(ref lformula, ref rformula)
+            | Formula::Biconditional(ref lformula, ref rformula) => {
+                self.collect_from_formula(&lformula);
+                self.collect_from_formula(&rformula);
+            }
+            Formula::Negation(ref rformula) => self.collect_from_formula(&rformula),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::term;
+    use crate::parser::{parse_statement, parse_statements, Degree, Subscript};
+    use crate::validity::TruthTreeMethod;
+
+    #[test]
+    fn interpretation_falsifies_invalid_argument() {
+        // The premises of ∀x(F¹x ⊃ G¹x), G¹a ∴ F¹a and the negation of its conclusion
+        let statements = parse_statements("{∀x(F¹x ⊃ G¹x), G¹a, ~F¹a}");
+        let truth_tree = TruthTreeMethod::new(&statements).compute();
+
+        let interpretation = truth_tree.interpretation().unwrap();
+
+        assert!(statements.iter().all(|x| interpretation.satisfies(x)));
+
+        let f = PredicateLetter('F', Subscript(None), Degree(1));
+        let g = PredicateLetter('G', Subscript(None), Degree(1));
+        let a = interpretation.denotation(&term('a')).unwrap();
+
+        assert_eq!(interpretation.domain(), &[a]);
+        assert!(!interpretation.extension(&f).unwrap().contains(&vec![a]));
+        assert!(interpretation.extension(&g).unwrap().contains(&vec![a]));
+    }
+
+    #[test]
+    fn interpretation_of_simple_statement_letters() {
+        let statements = parse_statements("{(A ∨ B), ~A}");
+        let truth_tree = TruthTreeMethod::new(&statements).compute();
+
+        let interpretation = truth_tree.interpretation().unwrap();
+
+        assert_eq!(
+            interpretation.truth_value(&SimpleStatementLetter('A', Subscript(None))),
+            Some(false)
+        );
+        assert_eq!(
+            interpretation.truth_value(&SimpleStatementLetter('B', Subscript(None))),
+            Some(true)
+        );
+
+        // Even without singular terms, the domain isn't empty
+        assert_eq!(interpretation.domain().len(), 1);
+    }
+
+    #[test]
+    fn identical_singular_terms_denote_same_object() {
+        let statements = parse_statements("{a = b, F¹a, ~c = b}");
+        let truth_tree = TruthTreeMethod::new(&statements).compute();
+
+        let interpretation = truth_tree.interpretation().unwrap();
+
+        assert_eq!(
+            interpretation.denotation(&term('a')),
+            interpretation.denotation(&term('b'))
+        );
+        assert_ne!(
+            interpretation.denotation(&term('a')),
+            interpretation.denotation(&term('c'))
+        );
+        assert_eq!(interpretation.domain().len(), 2);
+    }
+
+    #[test]
+    fn closed_branch_has_no_interpretation() {
+        let statements = parse_statements("{A, ~A}");
+        let truth_tree = TruthTreeMethod::new(&statements).compute();
+
+        assert!(truth_tree.interpretation().is_none());
+        assert!(truth_tree
+            .interpretation_from_branch(&truth_tree.main_trunk_id())
+            .is_none());
+    }
+
+    #[test]
+    fn unfinished_branch_has_no_interpretation() {
+        let statements = parse_statements("{(A ∨ B)}");
+        let truth_tree = TruthTreeMethod::new(&statements).compute();
+
+        // The main trunk isn't the end of the tree
+        assert!(truth_tree
+            .interpretation_from_branch(&truth_tree.main_trunk_id())
+            .is_none());
+    }
+
+    #[test]
+    fn evaluates_in_supplied_interpretation() {
+        let l = PredicateLetter('L', Subscript(None), Degree(2));
+
+        // Everyone loves someone, but b loves no one
+        let interpretation = Interpretation::new(3)
+            .and_then(|x| x.with_denotation(term('a'), Object(0)))
+            .and_then(|x| x.with_denotation(term('b'), Object(1)))
+            .and_then(|x| {
+                x.with_extension(
+                    l.clone(),
+                    vec![vec![Object(0), Object(1)], vec![Object(2), Object(2)]],
+                )
+            })
+            .unwrap()
+            .with_truth_value(SimpleStatementLetter('A', Subscript(None)), true);
+
+        let values =
+            parse_statements("{∃xL²ax, ∀x∃yL²xy, ∃x(L²xx & ~x = a), (A ⊃ ∀x~L²bx), ~a = b}")
+                .iter()
+                .map(|x| interpretation.evaluate(x))
+                .collect::<Vec<_>>();
+
+        assert_eq!(
+            values,
+            vec![Ok(true), Ok(false), Ok(true), Ok(true), Ok(true)]
+        );
+
+        // Formulas with free variables are evaluated on some assignment of objects to them
+        let formula = Formula::Predicate(
+            l,
+            vec![
+                Term::Variable(Variable('x', Subscript(None))),
+                Term::SingularTerm(term('b')),
+            ],
+        );
+        let mut assignment = HashMap::new();
+
+        assert_eq!(
+            interpretation.evaluate_formula(&formula, &assignment),
+            Err(EvaluationError::UnassignedVariable(Variable(
+                'x',
+                Subscript(None)
+            )))
+        );
+
+        assignment.insert(Variable('x', Subscript(None)), Object(0));
+
+        assert_eq!(
+            interpretation.evaluate_formula(&formula, &assignment),
+            Ok(true)
+        );
+    }
+
+    #[test]
+    fn evaluation_fails_on_uninterpreted_symbols() {
+        let interpretation = Interpretation::new(1)
+            .and_then(|x| x.with_denotation(term('a'), Object(0)))
+            .unwrap()
+            .with_truth_value(SimpleStatementLetter('A', Subscript(None)), false);
+
+        // Even though the conjunction is false whatever the value of B is
+        match interpretation.evaluate(&parse_statement("(A & B)")) {
+            Err(EvaluationError::UninterpretedSimpleStatementLetter(letter)) => {
+                assert_eq!(letter, SimpleStatementLetter('B', Subscript(None)))
+            }
+            _ => assert!(false),
+        }
+
+        match interpretation.evaluate(&parse_statement("∀xF¹x")) {
+            Err(EvaluationError::UninterpretedPredicateLetter(pred_letter)) => {
+                assert_eq!(
+                    pred_letter,
+                    PredicateLetter('F', Subscript(None), Degree(1))
+                )
+            }
+            _ => assert!(false),
+        }
+
+        match interpretation.evaluate(&parse_statement("a = c")) {
+            Err(e @ EvaluationError::UninterpretedSingularTerm(_)) => {
+                assert_eq!(e.to_string(), "singular term c doesn't denote any object")
+            }
+            _ => assert!(false),
+        }
+    }
+
+    #[test]
+    fn rejects_ill_formed_interpretations() {
+        let l = PredicateLetter('L', Subscript(None), Degree(2));
+
+        assert_eq!(Interpretation::new(0), Err(EvaluationError::EmptyDomain));
+
+        let interpretation = Interpretation::new(2).unwrap();
+
+        assert_eq!(
+            interpretation.clone().with_denotation(term('a'), Object(2)),
+            Err(EvaluationError::ObjectNotInDomain(Object(2)))
+        );
+
+        match interpretation
+            .clone()
+            .with_extension(l.clone(), vec![vec![Object(0)]])
+        {
+            Err(
+                e @ EvaluationError::ArityMismatch {
+                    expected: 2,
+                    found: 1,
+                    ..
+                },
+            ) => assert_eq!(
+                e.to_string(),
+                "predicate letter L² is true of tuples of 2 objects, not 1"
+            ),
+            _ => assert!(false),
+        }
+
+        assert_eq!(
+            interpretation
+                .clone()
+                .with_extension(l.clone(), vec![vec![Object(0), Object(5)]]),
+            Err(EvaluationError::ObjectNotInDomain(Object(5)))
+        );
+
+        let formula = Formula::Predicate(l, vec![Term::Variable(Variable('x', Subscript(None)))]);
+        let mut assignment = HashMap::new();
+        assignment.insert(Variable('x', Subscript(None)), Object(3));
+
+        assert_eq!(
+            interpretation.evaluate_formula(&formula, &assignment),
+            Err(EvaluationError::ObjectNotInDomain(Object(3)))
+        );
+    }
+}
@@ -1,9 +1,17 @@
 mod algorithm;
+mod interpretation;
+mod truth_table;
 mod truth_tree;
 
-pub use self::algorithm::{DerivationId, Rule, TruthTreeMethod};
+pub use self::algorithm::{
+    CancellationToken, DerivationId, Interruption, Limits, Rule, Step, Steps, TruthTreeMethod,
+};
+pub(crate) use self::algorithm::first_unused_in_singular_term_stack;
+pub use self::interpretation::{EvaluationError, Interpretation, Object};
+pub use self::truth_table::{TruthTable, TruthTableRow};
 pub use self::truth_tree::{
-    Branch, BranchDirectDescendantsIdsIter, BranchDirectDescendantsIter, BranchNode,
-    BranchNodeLocation, DownwardsBranchesIdsIter, DownwardsBranchesIter, StatementIdsIter,
-    StatementsIter, TreeId, TruthTree, UpwardsBranchesIdsIter, UpwardsBranchesIter,
+    Branch, BranchDirectDescendantsIdsIter, BranchDirectDescendantsIter, BranchLayout, BranchNode,
+    BranchNodeLocation, DownwardsBranchesIdsIter, DownwardsBranchesIter, Justification, Layout,
+    Listing, ListingEntry, ListingLine, StatementIdsIter, StatementLayout, StatementsIter, TreeId,
+    TruthTree, UpwardsBranchesIdsIter, UpwardsBranchesIter,
 };
@@ -0,0 +1,329 @@
+use super::Interpretation;
+use crate::parser::{SimpleStatementLetter, Statement};
+use std::fmt;
+
+/// A truth table of some statements that are built only from simple statement letters and
+/// connectives, as computed by e.g. [Argument::truth_table](struct.Argument.html#method.truth_table).
+///
+/// There is a column for each simple statement letter, in order of appearance, and then one
+/// for each sub-statement of the statements that isn't a simple statement letter, each after
+/// the columns of its own sub-statements. There is a row for each assignment of truth values
+/// to the simple statement letters (so `2^n` rows for `n` letters), starting with every letter
+/// true and ending with every letter false. Since that grows quickly, there are no truth
+/// tables of statements with more than [MAX_LETTERS](#associatedconstant.MAX_LETTERS) simple
+/// statement letters.
+///
+/// Which rows are highlighted depends on what the truth table was computed for (e.g. for an
+/// argument, they're the rows on which the premises are true and the conclusion false).
+///
+/// The [Display](https://doc.rust-lang.org/std/fmt/trait.Display.html) implementation
+/// writes the table in plain text, with the truth value of each statement below its main
+/// connective, and highlighted rows marked with `*`, e.g.:
+///
+/// ```text
+/// A | B | (A & B)
+/// T | T |    T
+/// T | F |    F     *
+/// F | T |    F     *
+/// F | F |    F     *
+/// ```
+#[derive(Debug, Clone)]
+pub struct TruthTable {
+    letters: Vec<SimpleStatementLetter>,
+    columns: Vec<Statement>,
+    rows: Vec<TruthTableRow>,
+}
+
+/// A row of a [TruthTable](struct.TruthTable.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct TruthTableRow {
+    /// The truth value of each simple statement letter, in the order of
+    /// [TruthTable::letters](struct.TruthTable.html#method.letters).
+    pub truth_values: Vec<bool>,
+    /// The truth value of each sub-statement, in the order of
+    /// [TruthTable::columns](struct.TruthTable.html#method.columns).
+    pub values: Vec<bool>,
+    pub highlighted: bool,
+}
+
+impl TruthTable {
+    /// The most simple statement letters a truth table can have (i.e. it has at most
+    /// `2^16` rows).
+    pub const MAX_LETTERS: usize = 16;
+
+    // Computes the truth table of 'statements', highlighting the rows for which 'highlight'
+    // returns true when given the truth value of each of them. Returns None if some statement
+    // isn't built only from simple statement letters and connectives, or if there are more
+    // than MAX_LETTERS letters
+    pub(crate) fn new<F>(statements: &[Statement], highlight: F) -> Option<TruthTable>
+    where
+        F: Fn(&[bool]) -> bool,
+    {
+        let mut letters = vec![];
+        let mut columns = vec![];
+
+        for statement in statements {
+            if !add_columns(statement, &mut letters, &mut columns) {
+                return None;
+            }
+        }
+
+        if letters.len() > TruthTable::MAX_LETTERS {
+            return None;
+        }
+
+        let rows = (0..1 << letters.len())
+            .map(|i| {
+                // The first letter changes the slowest, from true to false
+                let truth_values = (0..letters.len())
+                    .map(|j| (i >> (letters.len() - 1 - j)) & 1 == 0)
+                    .collect::<Vec<_>>();
+
+                // Only the truth values matter, but the domain can't be empty
+                let interpretation = letters.iter().zip(&truth_values).fold(
+                    Interpretation::new(1).unwrap(),
+                    |interpretation, (letter, truth_value)| {
+                        interpretation.with_truth_value(letter.clone(), *truth_value)
+                    },
+                );
+
+                // Every letter has a truth value, and add_columns checked that there is
+                // nothing else to interpret
+                let value = |statement| interpretation.evaluate(statement).unwrap();
+
+                TruthTableRow {
+                    values: columns.iter().map(value).collect(),
+                    highlighted: highlight(&statements.iter().map(value).collect::<Vec<_>>()),
+                    truth_values,
+                }
+            })
+            .collect();
+
+        Some(TruthTable {
+            letters,
+            columns,
+            rows,
+        })
+    }
+
+    /// Returns the simple statement letters, in the order of the first columns.
+    pub fn letters(&self) -> &[SimpleStatementLetter] {
+        &self.letters
+    }
+
+    /// Returns the sub-statements that aren't simple statement letters, in the order of
+    /// the columns after those of the letters.
+    pub fn columns(&self) -> &[Statement] {
+        &self.columns
+    }
+
+    /// Returns the rows of the truth table, in order.
+    pub fn rows(&self) -> &[TruthTableRow] {
+        &self.rows
+    }
+
+    /// Returns an iterator over the highlighted rows of the truth table, in order.
+    pub fn highlighted_rows(&self) -> impl Iterator<Item = &TruthTableRow> {
+        self.rows.iter().filter(|x| x.highlighted)
+    }
+}
+
+impl fmt::Display for TruthTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let headers = self
+            .letters
+            .iter()
+            .map(|x| Statement::Simple(x.clone()).to_string())
+            .chain(self.columns.iter().map(|x| x.to_string()))
+            .collect::<Vec<_>>();
+
+        // The truth value of a statement goes below its main connective (or, for simple
+        // statement letters, the letter itself)
+        let offsets = self
+            .letters
+            .iter()
+            .map(|_| 0)
+            .chain(self.columns.iter().map(main_connective_offset))
+            .collect::<Vec<_>>();
+
+        writeln!(f, "{}", headers.join(" | "))?;
+
+        for row in &self.rows {
+            let mut line = String::new();
+
+            for (i, value) in row.truth_values.iter().chain(&row.values).enumerate() {
+                if i > 0 {
+                    line.push_str(" | ");
+                }
+
+                let width = headers[i].chars().count();
+                let value = if *value { "T" } else { "F" };
+
+                line.push_str(&format!(
+                    "{:3$}{}{:4$}",
+                    "",
+                    value,
+                    "",
+                    offsets[i],
+                    width - offsets[i] - 1
+                ));
+            }
+
+            if row.highlighted {
+                line.push_str("  *");
+            }
+
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+// Adds the simple statement letters and the other sub-statements of 'statement' that aren't
+// there yet. Returns false if the statement isn't built only from simple statement letters
+// and connectives
+fn add_columns(
+    statement: &Statement,
+    letters: &mut Vec<SimpleStatementLetter>,
+    columns: &mut Vec<Statement>,
+) -> bool {
+    match statement {
+        Statement::Simple(letter) => {
+            if !letters.contains(letter) {
+                letters.push(letter.clone());
+            }
+
+            return true;
+        }
+        Statement::LogicalNegation(statement) => {
+            if !add_columns(statement, letters, columns) {
+                return false;
+            }
+        }
+        Statement::LogicalConjunction(lhs, rhs)
+        | Statement::LogicalDisjunction(lhs, rhs)
+        | Statement::LogicalConditional(lhs, rhs)
+        | Statement::LogicalBiconditional(lhs, rhs) => {
+            if !add_columns(lhs, letters, columns) || !add_columns(rhs, letters, columns) {
+                return false;
+            }
+        }
+        Statement::Singular(..)
+        | Statement::Identity(..)
+        | Statement::Existential(..)
+        | Statement::Universal(..) => return false,
+    }
+
+    if !columns.contains(statement) {
+        columns.push(statement.clone());
+    }
+
+    true
+}
+
+// Offset, in characters, of the main connective of some statement that isn't a simple
+// statement letter, when displayed
+fn main_connective_offset(statement: &Statement) -> usize {
+    match statement {
+        Statement::LogicalNegation(_) => 0,
+        Statement::LogicalConjunction(lhs, _)
+        | Statement::LogicalDisjunction(lhs, _)
+        | Statement::LogicalConditional(lhs, _)
+        | Statement::LogicalBiconditional(lhs, _) => lhs.to_string().chars().count() + 2,
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{parse_statements, Subscript};
+    use crate::validity::TruthTreeMethod;
+
+    #[test]
+    fn columns_and_rows() {
+        let truth_table = TruthTable::new(&parse_statements("{~(A & B), (B ∨ A)}"), |x| {
+            x.iter().all(|x| *x)
+        })
+        .unwrap();
+
+        assert_eq!(
+            truth_table
+                .letters()
+                .iter()
+                .map(|x| Statement::Simple(x.clone()).to_string())
+                .collect::<Vec<_>>(),
+            vec!["A", "B"]
+        );
+        assert_eq!(
+            truth_table
+                .columns()
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>(),
+            vec!["(A & B)", "~(A & B)", "(B ∨ A)"]
+        );
+
+        let rows = truth_table.rows();
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(rows[1].truth_values, vec![true, false]);
+        assert_eq!(rows[1].values, vec![false, true, true]);
+        assert_eq!(
+            rows.iter().map(|x| x.highlighted).collect::<Vec<_>>(),
+            vec![false, true, true, false]
+        );
+
+        assert_eq!(
+            truth_table.to_string(),
+            "A | B | (A & B) | ~(A & B) | (B ∨ A)\n\
+             T | T |    T    | F        |    T\n\
+             T | F |    F    | T        |    T     *\n\
+             F | T |    F    | T        |    T     *\n\
+             F | F |    F    | T        |    F\n"
+        );
+
+        // Only statements built from simple statement letters and connectives have truth tables
+        assert!(TruthTable::new(&parse_statements("{A, (B ⊃ F¹a)}"), |_| false).is_none());
+        assert!(TruthTable::new(&parse_statements("{∀xF¹x}"), |_| false).is_none());
+    }
+
+    #[test]
+    fn limits_number_of_letters() {
+        let letters = |n| {
+            (0..n)
+                .map(|x| Statement::Simple(SimpleStatementLetter('A', Subscript(Some(x + 1)))))
+                .collect::<Vec<_>>()
+        };
+
+        let truth_table = TruthTable::new(&letters(5), |_| false).unwrap();
+
+        assert_eq!(truth_table.rows().len(), 32);
+        assert!(TruthTable::new(&letters(TruthTable::MAX_LETTERS as u64 + 1), |_| false).is_none());
+    }
+
+    #[test]
+    fn agrees_with_truth_trees() {
+        for input in &[
+            "{A, ~A}",
+            "{(A ⊃ B), A, ~B}",
+            "{(A ≡ B), (B ∨ C), ~(A & C)}",
+            "{~((A ⊃ B) ∨ (B ⊃ A))}",
+            "{(A₁ ∨ ~B), (B ≡ ~A₁), ((C & A₁) ⊃ B)}",
+        ] {
+            let statements = parse_statements(input);
+
+            let truth_table = TruthTable::new(&statements, |x| x.iter().all(|x| *x)).unwrap();
+            let truth_tree = TruthTreeMethod::new(&statements).compute();
+
+            // A set is consistent if all its statements are true on some row
+            assert_eq!(
+                truth_table.highlighted_rows().next().is_some(),
+                truth_tree.is_open(),
+                "{}",
+                input
+            );
+        }
+    }
+}
@@ -8,7 +8,10 @@ use id_tree::InsertBehavior::*;
 use id_tree::*;
 
 /// A location to some node in the truth tree.
-#[derive(Clone, Debug)]
+///
+/// **Serialization of this struct requires the feature `serde_support` to be enabled.**
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde_support", derive(Serialize))]
 pub struct BranchNodeLocation {
     pub node_id: TreeId,
     pub branch_id: TreeId,
@@ -31,7 +34,7 @@ pub struct BranchNode {
 /// The nodes of the branch are guaranteed to be in order of derivation.
 pub struct Branch {
     children: Tree<BranchNode>,
-    closed: bool,
+    closed_by: Option<(BranchNodeLocation, BranchNodeLocation)>,
 }
 
 impl Branch {
@@ -53,20 +56,29 @@ impl Branch {
 
         Branch {
             children: tree,
-            closed: false,
+            closed_by: None,
         }
     }
 
-    pub(in crate::validity) fn close(&mut self) {
-        self.closed = true;
+    pub(in crate::validity) fn close(&mut self, closed_by: (BranchNodeLocation, BranchNodeLocation)) {
+        self.closed_by = Some(closed_by);
     }
 
     pub fn is_closed(&self) -> bool {
-        self.closed
+        self.closed_by.is_some()
+    }
+
+    /// If this branch is closed, returns the locations of the two statements
+    /// that contradict each other, the second being the one that closed the branch.
+    ///
+    /// A statement of the form `~a = a` contradicts itself, in which case both
+    /// locations are the same.
+    pub fn closed_by(&self) -> Option<&(BranchNodeLocation, BranchNodeLocation)> {
+        self.closed_by.as_ref()
     }
 
     pub(in crate::validity) fn append_statement(&mut self, statement: BranchNode) -> TreeId {
-        assert!(!self.closed, "attempt to append statement to closed branch");
+        assert!(!self.is_closed(), "attempt to append statement to closed branch");
 
         let last_child_id = self.statement_ids().last().unwrap();
 
@@ -122,7 +134,7 @@ mod tests {
     fn branch_new() {
         let branch = Branch::new(vec![BRANCH_NODE_1.clone(), BRANCH_NODE_2.clone()]);
 
-        assert!(!branch.closed, "branch was closed");
+        assert!(branch.closed_by.is_none(), "branch was closed");
 
         let mut root_children_iter = branch
             .children
@@ -163,9 +175,16 @@ mod tests {
     fn branch_close() {
         let mut branch = Branch::new(vec![BRANCH_NODE_1.clone()]);
 
-        branch.close();
+        // The branch has no ID of its own outside of a truth tree
+        let statement_id = branch.statement_ids().next().unwrap();
+        let location = BranchNodeLocation {
+            node_id: statement_id.clone(),
+            branch_id: statement_id,
+        };
+
+        branch.close((location.clone(), location.clone()));
 
-        assert!(branch.closed, "branch was not closed");
+        assert_eq!(branch.closed_by, Some((location.clone(), location)));
     }
 
     #[test]
@@ -0,0 +1,167 @@
+use super::branch_ids_left_to_right;
+use crate::validity::truth_tree::{TreeId, TruthTree};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+impl TruthTree {
+    /// Returns a description of the truth tree in the DOT language of
+    /// [Graphviz](https://graphviz.org/), e.g. to render it with `dot -Tsvg`.
+    ///
+    /// Each branch is drawn as a cluster of its statements, in order. Each derived statement
+    /// has a dashed edge from the statement it was derived from, labelled with the rule
+    /// that was applied. Closed branches are drawn in red and end with a `×`.
+    pub fn to_dot(&self) -> String {
+        let branch_ids = branch_ids_left_to_right(self, self.main_trunk_id());
+
+        // DOT node names, in the order they're written
+        let mut node_names = HashMap::new();
+
+        for branch_id in &branch_ids {
+            for statement_id in self.branch_from_id(branch_id).statement_ids() {
+                let name = format!("n{}", node_names.len());
+                node_names.insert(statement_id, name);
+            }
+        }
+
+        let mut dot = String::new();
+
+        writeln!(dot, "digraph truth_tree {{").unwrap();
+        writeln!(dot, "    node [shape=plaintext];").unwrap();
+
+        for (i, branch_id) in branch_ids.iter().enumerate() {
+            let branch = self.branch_from_id(branch_id);
+
+            writeln!(dot).unwrap();
+            writeln!(dot, "    subgraph cluster_{} {{", i).unwrap();
+
+            if branch.is_closed() {
+                writeln!(dot, "        color=red;").unwrap();
+            }
+
+            for (statement_id, branch_node) in branch.statements() {
+                writeln!(
+                    dot,
+                    "        {} [label=\"{}\"];",
+                    node_names[&statement_id],
+                    escape(&branch_node.statement.to_string())
+                )
+                .unwrap();
+            }
+
+            if branch.is_closed() {
+                writeln!(dot, "        closed_{} [label=\"×\"];", i).unwrap();
+            }
+
+            // Statements of a branch follow one another
+            let names = branch
+                .statement_ids()
+                .map(|x| node_names[&x].clone())
+                .chain(if branch.is_closed() {
+                    Some(format!("closed_{}", i))
+                } else {
+                    None
+                })
+                .collect::<Vec<_>>();
+
+            if names.len() > 1 {
+                writeln!(dot, "        {};", names.join(" -> ")).unwrap();
+            }
+
+            writeln!(dot, "    }}").unwrap();
+
+            // Child branches follow the last statement of their parent branch
+            for child_branch_id in self.traverse_branch_direct_descendants_ids(branch_id) {
+                writeln!(
+                    dot,
+                    "    {} -> {};",
+                    node_names[&last_statement_id(self, branch_id)],
+                    node_names[&first_statement_id(self, &child_branch_id)]
+                )
+                .unwrap();
+            }
+
+            for (statement_id, branch_node) in branch.statements() {
+                if let Some((ref location, ref rule, _)) = branch_node.derived_from {
+                    writeln!(
+                        dot,
+                        "    {} -> {} [style=dashed, constraint=false, label=\"{}\"];",
+                        node_names[&location.node_id],
+                        node_names[&statement_id],
+                        escape(&rule.to_string())
+                    )
+                    .unwrap();
+                }
+            }
+        }
+
+        writeln!(dot, "}}").unwrap();
+
+        dot
+    }
+}
+
+fn first_statement_id(truth_tree: &TruthTree, branch_id: &TreeId) -> TreeId {
+    truth_tree
+        .branch_from_id(branch_id)
+        .statement_ids()
+        .next()
+        .unwrap()
+}
+
+fn last_statement_id(truth_tree: &TruthTree, branch_id: &TreeId) -> TreeId {
+    truth_tree
+        .branch_from_id(branch_id)
+        .statement_ids()
+        .last()
+        .unwrap()
+}
+
+// Escapes a string to be put between double quotes
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_statements;
+    use crate::validity::TruthTreeMethod;
+
+    #[test]
+    fn truth_tree_to_dot() {
+        let truth_tree = TruthTreeMethod::new(&parse_statements("{(A ∨ B), ~A}")).compute();
+
+        assert_eq!(
+            truth_tree.to_dot(),
+            "digraph truth_tree {
+    node [shape=plaintext];
+
+    subgraph cluster_0 {
+        n0 [label=\"(A ∨ B)\"];
+        n1 [label=\"~A\"];
+        n0 -> n1;
+    }
+    n1 -> n2;
+    n1 -> n3;
+
+    subgraph cluster_1 {
+        color=red;
+        n2 [label=\"A\"];
+        closed_1 [label=\"×\"];
+        n2 -> closed_1;
+    }
+    n0 -> n2 [style=dashed, constraint=false, label=\"Disjunction\"];
+
+    subgraph cluster_2 {
+        n3 [label=\"B\"];
+    }
+    n0 -> n3 [style=dashed, constraint=false, label=\"Disjunction\"];
+}
+"
+        );
+    }
+
+    #[test]
+    fn escapes_labels() {
+        assert_eq!(super::escape("a \"b\" \\c"), "a \\\"b\\\" \\\\c");
+    }
+}
@@ -0,0 +1,175 @@
+use super::rows;
+use crate::parser::{DisplayOptions, Notation, Parenthesization};
+use crate::validity::algorithm::Rule;
+use crate::validity::truth_tree::{TreeId, TruthTree};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+impl TruthTree {
+    /// Returns the truth tree as a `prooftree` environment of the LaTeX package
+    /// [prooftrees](https://ctan.org/pkg/prooftrees) (which is built on top of `forest`),
+    /// to be included in a document that loads it with `\usepackage{prooftrees}`.
+    ///
+    /// Statements are typeset in math mode (see [Notation::Latex](enum.Notation.html#variant.Latex)),
+    /// with the same line numbers as [to_text](#method.to_text). Each derived statement is justified
+    /// by the number of the line it was derived from and the rule that was applied. Closed branches
+    /// end with a `×`, and open branches with a `↑` if the truth tree algorithm finished.
+    pub fn to_latex(&self) -> String {
+        let branch_ids = super::branch_ids_left_to_right(self, self.main_trunk_id());
+        let rows = rows(self, &branch_ids);
+
+        let mut latex = String::new();
+
+        writeln!(latex, "\\begin{{prooftree}}{{}}").unwrap();
+        self.write_latex_branch(&mut latex, &self.main_trunk_id(), &rows, 0, 0);
+        writeln!(latex, "\\end{{prooftree}}").unwrap();
+
+        latex
+    }
+
+    fn write_latex_branch(
+        &self,
+        latex: &mut String,
+        branch_id: &TreeId,
+        rows: &HashMap<TreeId, usize>,
+        depth: usize,
+        first_row: usize,
+    ) {
+        let options = DisplayOptions {
+            notation: Notation::Latex,
+            parenthesization: Parenthesization::Minimal,
+        };
+
+        let branch = self.branch_from_id(branch_id);
+        let statements = branch.statements().collect::<Vec<_>>();
+        let is_last_child = self.branch_is_last_child(branch_id);
+
+        // The row each statement would be on without aligning it with others
+        let mut next_row = first_row;
+
+        for (i, (statement_id, branch_node)) in statements.iter().enumerate() {
+            // The statement is enclosed in braces so that forest doesn't mistake any of
+            // its characters for the start of the node's options
+            let mut node = format!("[{{{}}}", branch_node.statement.display_with(options));
+
+            if let Some((ref location, ref rule, _)) = branch_node.derived_from {
+                write!(
+                    node,
+                    ", just={{{}, {}}}",
+                    rows[&location.node_id] + 1,
+                    rule_to_latex(rule)
+                )
+                .unwrap();
+            }
+
+            let row = rows[statement_id];
+
+            if row > next_row {
+                write!(node, ", move by={}", row - next_row).unwrap();
+            }
+
+            next_row = row + 1;
+
+            if i == statements.len() - 1 && is_last_child {
+                if branch.is_closed() {
+                    write!(node, ", close").unwrap();
+                } else if self.is_complete() {
+                    write!(node, ", open").unwrap();
+                }
+            }
+
+            writeln!(latex, "{}{}", "  ".repeat(depth + i), node).unwrap();
+        }
+
+        for child_branch_id in self.traverse_branch_direct_descendants_ids(branch_id) {
+            self.write_latex_branch(
+                latex,
+                &child_branch_id,
+                rows,
+                depth + statements.len(),
+                next_row,
+            );
+        }
+
+        for i in (0..statements.len()).rev() {
+            writeln!(latex, "{}]", "  ".repeat(depth + i)).unwrap();
+        }
+    }
+}
+
+fn rule_to_latex(rule: &Rule) -> &'static str {
+    match rule {
+        Rule::QuantifierExchange => "QE",
+        Rule::ExistentialQuantifier => "$\\exists$",
+        Rule::UniversalQuantifier => "$\\forall$",
+        Rule::DoubleNegation => "${\\sim}{\\sim}$",
+        Rule::Conjunction => "\\&",
+        Rule::NegationOfConditional => "${\\sim}\\supset$",
+        Rule::NegationOfDisjunction => "${\\sim}\\lor$",
+        Rule::Conditional => "$\\supset$",
+        Rule::NegationOfConjunction => "${\\sim}\\&$",
+        Rule::Disjunction => "$\\lor$",
+        Rule::Biconditional => "$\\equiv$",
+        Rule::NegationOfBiconditional => "${\\sim}\\equiv$",
+        Rule::Identity => "$=$",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_statements;
+    use crate::validity::TruthTreeMethod;
+
+    #[test]
+    fn truth_tree_to_latex() {
+        let truth_tree = TruthTreeMethod::new(&parse_statements("{(A ∨ B), ~A}")).compute();
+
+        assert_eq!(
+            truth_tree.to_latex(),
+            "\\begin{prooftree}{}
+[{(A \\lor B)}
+  [{{\\sim}A}
+    [{A}, just={1, $\\lor$}, close
+    ]
+    [{B}, just={1, $\\lor$}, open
+    ]
+  ]
+]
+\\end{prooftree}
+"
+        );
+    }
+
+    #[test]
+    fn aligned_statements_are_moved_down() {
+        let truth_tree =
+            TruthTreeMethod::new(&parse_statements("{(A ∨ (B & C)), (C ∨ D)}")).compute();
+
+        assert_eq!(
+            truth_tree.to_latex(),
+            "\\begin{prooftree}{}
+[{(A \\lor (B \\& C))}
+  [{(C \\lor D)}
+    [{A}, just={1, $\\lor$}
+      [{C}, just={2, $\\lor$}, move by=2, open
+      ]
+      [{D}, just={2, $\\lor$}, move by=2, open
+      ]
+    ]
+    [{(B \\& C)}, just={1, $\\lor$}
+      [{B}, just={3, \\&}
+        [{C}, just={3, \\&}
+          [{C}, just={2, $\\lor$}, open
+          ]
+          [{D}, just={2, $\\lor$}, open
+          ]
+        ]
+      ]
+    ]
+  ]
+]
+\\end{prooftree}
+"
+        );
+    }
+}
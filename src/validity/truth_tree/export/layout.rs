@@ -0,0 +1,262 @@
+use super::{branch_ids_left_to_right, rows};
+use crate::validity::truth_tree::{TreeId, TruthTree};
+use std::collections::HashMap;
+
+// Horizontal space between sibling branches
+const GAP: usize = 3;
+
+/// Where each statement of a truth tree goes in a drawing of it, as computed by
+/// [TruthTree::layout](struct.TruthTree.html#method.layout).
+///
+/// Positions are measured in characters horizontally and in rows vertically, starting
+/// at 0 on the top left. Row `n` holds the statements on line number `n + 1`.
+#[derive(Debug, Clone)]
+pub struct Layout {
+    width: usize,
+    height: usize,
+    statements: HashMap<TreeId, StatementLayout>,
+    branches: HashMap<TreeId, BranchLayout>,
+}
+
+/// Where some statement goes in a drawing of a truth tree. See [Layout](struct.Layout.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatementLayout {
+    /// The statement, followed by its justification if it was derived
+    /// (e.g. `A [1, ∨]`).
+    pub label: String,
+    /// The column of the first character of the label.
+    pub column: usize,
+    pub row: usize,
+}
+
+/// Where some branch goes in a drawing of a truth tree. See [Layout](struct.Layout.html).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BranchLayout {
+    /// The first column of the space taken by the branch and its descendants.
+    pub column: usize,
+    /// The width of the space taken by the branch and its descendants.
+    pub width: usize,
+    /// The column of the middle of the branch, which the statements of the branch
+    /// are centered on.
+    pub center: usize,
+    /// The row right after the last statement of the branch.
+    pub end_row: usize,
+    /// What goes on `end_row` if the branch is at the end of the tree: `×` if
+    /// it's closed, `↑` if it's open, or `⋮` if the truth tree algorithm was
+    /// interrupted before finishing it.
+    pub marker: Option<char>,
+}
+
+impl Layout {
+    /// Returns the width of the whole drawing.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows of the whole drawing, including the markers at the
+    /// end of branches.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns where the statement `statement_id` goes.
+    ///
+    /// # Panics
+    /// Panics if the ID provided does not represent a statement from the truth tree.
+    pub fn statement(&self, statement_id: &TreeId) -> &StatementLayout {
+        self.statements.get(statement_id).expect("invalid id")
+    }
+
+    /// Returns where the branch `branch_id` goes.
+    ///
+    /// # Panics
+    /// Panics if the ID provided does not represent a branch from the truth tree.
+    pub fn branch(&self, branch_id: &TreeId) -> &BranchLayout {
+        self.branches.get(branch_id).expect("invalid id")
+    }
+}
+
+impl TruthTree {
+    /// Computes where each statement of the truth tree goes in a drawing of it, the way it
+    /// would be drawn by hand.
+    ///
+    /// Branches are laid out side by side, below their parent branch. Each branch is centered
+    /// over its children. Statements from the same application of a rule (i.e. with the same
+    /// [DerivationId](struct.DerivationId.html) and [index](struct.DerivationId.html#structfield.index))
+    /// are on the same row, even if on different branches. Each derived statement is labelled
+    /// with its justification, i.e. the number of the line it was derived from and the
+    /// [abbreviation](enum.Rule.html#method.abbreviation) of the rule.
+    pub fn layout(&self) -> Layout {
+        let branch_ids = branch_ids_left_to_right(self, self.main_trunk_id());
+        let rows = rows(self, &branch_ids);
+
+        let mut labels = HashMap::new();
+
+        for branch_id in &branch_ids {
+            for (statement_id, branch_node) in self.branch_from_id(branch_id).statements() {
+                let label = match branch_node.derived_from {
+                    Some((ref location, ref rule, _)) => format!(
+                        "{} [{}, {}]",
+                        branch_node.statement,
+                        rows[&location.node_id] + 1,
+                        rule.abbreviation()
+                    ),
+                    None => branch_node.statement.to_string(),
+                };
+
+                labels.insert(statement_id, label);
+            }
+        }
+
+        // Width of each branch, including its descendants. Children come after their parents
+        // in branch_ids, so going backwards all children are done before their parents.
+        let mut widths = HashMap::new();
+
+        for branch_id in branch_ids.iter().rev() {
+            let own_width = self
+                .branch_from_id(branch_id)
+                .statement_ids()
+                .map(|x| labels[&x].chars().count())
+                .max()
+                .unwrap();
+
+            let children_width = children_width(self, branch_id, &widths);
+
+            widths.insert(branch_id.clone(), own_width.max(children_width));
+        }
+
+        // Column at which each branch starts
+        let mut columns = HashMap::new();
+        columns.insert(self.main_trunk_id(), 0);
+
+        for branch_id in &branch_ids {
+            let mut column = columns[branch_id]
+                + (widths[branch_id] - children_width(self, branch_id, &widths)) / 2;
+
+            for child_branch_id in self.traverse_branch_direct_descendants_ids(branch_id) {
+                columns.insert(child_branch_id.clone(), column);
+                column += widths[&child_branch_id] + GAP;
+            }
+        }
+
+        let mut branches = HashMap::new();
+        let mut statements = HashMap::new();
+
+        for branch_id in &branch_ids {
+            let branch = self.branch_from_id(branch_id);
+            let column = columns[branch_id];
+            let width = widths[branch_id];
+
+            for statement_id in branch.statement_ids() {
+                let label = labels.remove(&statement_id).unwrap();
+
+                statements.insert(
+                    statement_id.clone(),
+                    StatementLayout {
+                        column: column + (width - label.chars().count()) / 2,
+                        row: rows[&statement_id],
+                        label,
+                    },
+                );
+            }
+
+            let marker = if !self.branch_is_last_child(branch_id) {
+                None
+            } else if branch.is_closed() {
+                Some('×')
+            } else if self.is_complete() {
+                Some('↑')
+            } else {
+                Some('⋮')
+            };
+
+            branches.insert(
+                branch_id.clone(),
+                BranchLayout {
+                    column,
+                    width,
+                    center: column + (width - 1) / 2,
+                    end_row: rows[&branch.statement_ids().last().unwrap()] + 1,
+                    marker,
+                },
+            );
+        }
+
+        Layout {
+            width: widths[&self.main_trunk_id()],
+            height: branches.values().map(|x| x.end_row).max().unwrap() + 1,
+            statements,
+            branches,
+        }
+    }
+}
+
+// Width of the children of some branch, side by side
+fn children_width(
+    truth_tree: &TruthTree,
+    branch_id: &TreeId,
+    widths: &HashMap<TreeId, usize>,
+) -> usize {
+    let children_widths = truth_tree
+        .traverse_branch_direct_descendants_ids(branch_id)
+        .map(|x| widths[&x])
+        .collect::<Vec<_>>();
+
+    if children_widths.is_empty() {
+        0
+    } else {
+        children_widths.iter().sum::<usize>() + GAP * (children_widths.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_statements;
+    use crate::validity::TruthTreeMethod;
+
+    #[test]
+    fn truth_tree_layout() {
+        let truth_tree = TruthTreeMethod::new(&parse_statements("{(A ∨ B), ~A}")).compute();
+
+        let layout = truth_tree.layout();
+
+        assert_eq!(layout.width(), 19);
+        assert_eq!(layout.height(), 4);
+
+        let main_trunk_id = truth_tree.main_trunk_id();
+        let main_trunk = layout.branch(&main_trunk_id);
+
+        assert_eq!((main_trunk.column, main_trunk.width), (0, 19));
+        assert_eq!((main_trunk.end_row, main_trunk.marker), (2, None));
+
+        let statements = truth_tree
+            .branch_from_id(&main_trunk_id)
+            .statement_ids()
+            .map(|x| layout.statement(&x).clone())
+            .collect::<Vec<_>>();
+
+        assert_eq!(statements[0].label, "(A ∨ B)");
+        assert_eq!((statements[0].column, statements[0].row), (6, 0));
+        assert_eq!((statements[1].column, statements[1].row), (8, 1));
+
+        let children = truth_tree
+            .traverse_branch_direct_descendants_ids(&main_trunk_id)
+            .map(|x| {
+                let statement_id = truth_tree.branch_from_id(&x).statement_ids().next().unwrap();
+
+                (layout.branch(&x).clone(), layout.statement(&statement_id).clone())
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(children[0].0.column, 0);
+        assert_eq!(children[0].0.marker, Some('×'));
+        assert_eq!(children[1].0.column, 11);
+        assert_eq!(children[1].0.marker, Some('↑'));
+
+        // Both statements derived from (A ∨ B) are on the same row
+        assert_eq!(children[0].1.label, "A [1, ∨]");
+        assert_eq!(children[0].1.row, 2);
+        assert_eq!(children[1].1.label, "B [1, ∨]");
+        assert_eq!(children[1].1.row, 2);
+    }
+}
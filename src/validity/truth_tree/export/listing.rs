@@ -0,0 +1,246 @@
+use super::{branch_ids_left_to_right, rows};
+use crate::parser::Statement;
+use crate::validity::truth_tree::{TreeId, TruthTree};
+use crate::validity::Rule;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+
+/// A truth tree as a numbered list of lines, the way the book presents it, as computed by
+/// [TruthTree::listing](struct.TruthTree.html#method.listing).
+///
+/// Line numbers are the same as those of [TruthTree::layout](struct.TruthTree.html#method.layout)
+/// (and so of every drawing of the truth tree), so a line may hold statements from different
+/// branches, and some numbers may be skipped.
+///
+/// The [Display](https://doc.rust-lang.org/std/fmt/trait.Display.html) implementation
+/// writes one line of text per line of the listing, with the statements of each line
+/// from left to right followed by their justifications, e.g.:
+///
+/// ```text
+/// 1  (A ∨ B) ✓
+/// 2  ~A
+/// 3  A   B      1, ∨
+/// ```
+#[derive(Debug, Clone)]
+pub struct Listing {
+    lines: Vec<ListingLine>,
+    line_numbers: HashMap<TreeId, usize>,
+}
+
+/// A numbered line of a [Listing](struct.Listing.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListingLine {
+    pub number: usize,
+    /// The statements on this line, from the leftmost branch to the rightmost.
+    pub entries: Vec<ListingEntry>,
+}
+
+/// A statement on some line of a [Listing](struct.Listing.html).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListingEntry {
+    pub statement_id: TreeId,
+    /// The branch that the statement is in.
+    pub branch_id: TreeId,
+    pub statement: Statement,
+    /// How the statement was derived, or `None` if it's one of the initial statements.
+    pub justification: Option<Justification>,
+    /// Whether some rule was applied to the statement. Statements that rules may be
+    /// applied to over and over (i.e. with the universal quantifier and identity rules)
+    /// are never done with, so they are never marked as decomposed.
+    pub decomposed: bool,
+}
+
+/// The line number of the statement that some statement was derived from, and the rule
+/// that was applied to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Justification {
+    pub line: usize,
+    pub rule: Rule,
+}
+
+impl Listing {
+    /// Returns the lines of the listing, in order.
+    pub fn lines(&self) -> &[ListingLine] {
+        &self.lines
+    }
+
+    /// Returns the number of the line that the statement `statement_id` is on.
+    ///
+    /// # Panics
+    /// Panics if the ID provided does not represent a statement from the truth tree.
+    pub fn line_number(&self, statement_id: &TreeId) -> usize {
+        *self.line_numbers.get(statement_id).expect("invalid id")
+    }
+}
+
+impl fmt::Display for Justification {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}, {}", self.line, self.rule.abbreviation())
+    }
+}
+
+impl fmt::Display for Listing {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let number_width = self
+            .lines
+            .last()
+            .map_or(0, |x| x.number.to_string().len());
+
+        let statements = self
+            .lines
+            .iter()
+            .map(|line| {
+                line.entries
+                    .iter()
+                    .map(|x| {
+                        if x.decomposed {
+                            format!("{} ✓", x.statement)
+                        } else {
+                            x.statement.to_string()
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join("   ")
+            })
+            .collect::<Vec<_>>();
+
+        let statements_width = statements
+            .iter()
+            .map(|x| x.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        for (line, statements) in self.lines.iter().zip(statements) {
+            // Statements on the same line are usually derived from the same application
+            // of a rule, in which case the justification is only written once
+            let mut justifications = vec![];
+
+            for justification in line.entries.iter().filter_map(|x| x.justification.as_ref()) {
+                if !justifications.contains(&justification) {
+                    justifications.push(justification);
+                }
+            }
+
+            write!(f, "{:>2$}  {}", line.number, statements, number_width)?;
+
+            if !justifications.is_empty() {
+                write!(
+                    f,
+                    "{:1$}  {2}",
+                    "",
+                    statements_width - statements.chars().count(),
+                    justifications
+                        .iter()
+                        .map(|x| x.to_string())
+                        .collect::<Vec<_>>()
+                        .join("; ")
+                )?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl TruthTree {
+    /// Lists the statements of the truth tree on numbered lines, each with its
+    /// justification (i.e. the line it was derived from and the rule that was applied)
+    /// and whether it has been decomposed.
+    ///
+    /// See [Listing](struct.Listing.html).
+    pub fn listing(&self) -> Listing {
+        let branch_ids = branch_ids_left_to_right(self, self.main_trunk_id());
+        let rows = rows(self, &branch_ids);
+
+        let mut decomposed = HashSet::new();
+
+        for branch_id in &branch_ids {
+            for (_, branch_node) in self.branch_from_id(branch_id).statements() {
+                if let Some((ref location, ref rule, _)) = branch_node.derived_from {
+                    if *rule != Rule::UniversalQuantifier && *rule != Rule::Identity {
+                        decomposed.insert(location.node_id.clone());
+                    }
+                }
+            }
+        }
+
+        let mut lines = BTreeMap::new();
+
+        for branch_id in &branch_ids {
+            for (statement_id, branch_node) in self.branch_from_id(branch_id).statements() {
+                let entry = ListingEntry {
+                    statement_id: statement_id.clone(),
+                    branch_id: branch_id.clone(),
+                    statement: branch_node.statement.clone(),
+                    justification: branch_node.derived_from.as_ref().map(
+                        |(location, rule, _)| Justification {
+                            line: rows[&location.node_id] + 1,
+                            rule: rule.clone(),
+                        },
+                    ),
+                    decomposed: decomposed.contains(&statement_id),
+                };
+
+                lines
+                    .entry(rows[&statement_id] + 1)
+                    .or_insert_with(Vec::new)
+                    .push(entry);
+            }
+        }
+
+        Listing {
+            lines: lines
+                .into_iter()
+                .map(|(number, entries)| ListingLine { number, entries })
+                .collect(),
+            line_numbers: rows.into_iter().map(|(id, row)| (id, row + 1)).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Justification;
+    use crate::parser::parse_statements;
+    use crate::validity::{Rule, TruthTreeMethod};
+
+    #[test]
+    fn truth_tree_listing() {
+        let truth_tree = TruthTreeMethod::new(&parse_statements("{(A ∨ B), ~A}")).compute();
+
+        let listing = truth_tree.listing();
+        let lines = listing.lines();
+
+        assert_eq!(lines.len(), 3);
+
+        assert_eq!(lines[0].number, 1);
+        assert_eq!(lines[0].entries.len(), 1);
+        assert_eq!(lines[0].entries[0].justification, None);
+        assert!(lines[0].entries[0].decomposed);
+
+        assert_eq!(lines[1].number, 2);
+        assert!(!lines[1].entries[0].decomposed);
+
+        assert_eq!(lines[2].number, 3);
+        assert_eq!(lines[2].entries.len(), 2);
+
+        for entry in &lines[2].entries {
+            assert_eq!(
+                entry.justification,
+                Some(Justification {
+                    line: 1,
+                    rule: Rule::Disjunction
+                })
+            );
+            assert_eq!(listing.line_number(&entry.statement_id), 3);
+            assert!(!entry.decomposed);
+        }
+
+        assert_eq!(
+            listing.to_string(),
+            "1  (A ∨ B) ✓\n2  ~A\n3  A   B      1, ∨\n"
+        );
+    }
+}
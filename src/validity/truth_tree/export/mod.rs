@@ -0,0 +1,105 @@
+mod dot;
+mod latex;
+mod layout;
+mod listing;
+mod svg;
+mod text;
+
+pub use self::layout::{BranchLayout, Layout, StatementLayout};
+pub use self::listing::{Justification, Listing, ListingEntry, ListingLine};
+
+use crate::validity::truth_tree::{TreeId, TruthTree};
+use std::collections::HashMap;
+
+// Same as traverse_downwards_branches_ids, except that sibling branches
+// come in the order they were created (i.e. left to right)
+fn branch_ids_left_to_right(truth_tree: &TruthTree, branch_id: TreeId) -> Vec<TreeId> {
+    let mut branch_ids = vec![];
+    let mut stack = vec![branch_id];
+
+    while let Some(branch_id) = stack.pop() {
+        let mut children = truth_tree
+            .traverse_branch_direct_descendants_ids(&branch_id)
+            .collect::<Vec<_>>();
+        children.reverse();
+
+        stack.extend(children);
+        branch_ids.push(branch_id);
+    }
+
+    branch_ids
+}
+
+// Assigns each statement of the truth tree a row (starting at 0), the way it would be
+// drawn by hand: statements of a branch come one after the other, a branch starts
+// below the end of its parent branch, and statements from the same application of a rule
+// with the same DerivationId::index (e.g. A and B from (A ∨ B)) are on the same row.
+fn rows(truth_tree: &TruthTree, branch_ids: &[TreeId]) -> HashMap<TreeId, usize> {
+    let mut rows = HashMap::new();
+
+    // Statements that must be on the same row
+    let mut groups = HashMap::new();
+
+    for branch_id in branch_ids {
+        for (statement_id, branch_node) in truth_tree.branch_from_id(branch_id).statements() {
+            if let Some((_, _, ref derivation_id)) = branch_node.derived_from {
+                groups
+                    .entry((derivation_id.id, derivation_id.index))
+                    .or_insert_with(Vec::new)
+                    .push(statement_id.clone());
+            }
+
+            rows.insert(statement_id, 0);
+        }
+    }
+
+    // Moving a statement down to align it with others may push down the statements
+    // after it, which may in turn need to be aligned with others, and so on. Rows only
+    // ever increase, so eventually nothing moves.
+    loop {
+        let mut changed = false;
+
+        for branch_id in branch_ids {
+            let mut next_row = truth_tree
+                .traverse_upwards_branch_ids(branch_id)
+                .nth(1)
+                .map_or(0, |parent_id| {
+                    let last_statement_id = truth_tree
+                        .branch_from_id(&parent_id)
+                        .statement_ids()
+                        .last()
+                        .unwrap();
+
+                    rows[&last_statement_id] + 1
+                });
+
+            for statement_id in truth_tree.branch_from_id(branch_id).statement_ids() {
+                let row = rows.get_mut(&statement_id).unwrap();
+
+                if *row < next_row {
+                    *row = next_row;
+                    changed = true;
+                }
+
+                next_row = *row + 1;
+            }
+        }
+
+        for group in groups.values() {
+            let max_row = group.iter().map(|x| rows[x]).max().unwrap();
+
+            for statement_id in group {
+                let row = rows.get_mut(statement_id).unwrap();
+
+                if *row < max_row {
+                    *row = max_row;
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            return rows;
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::validity::truth_tree::TruthTree;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+// Sizes in pixels
+const CHAR_WIDTH: usize = 10;
+const ROW_HEIGHT: usize = 24;
+const MARGIN: usize = 12;
+const FONT_SIZE: usize = 16;
+
+impl TruthTree {
+    /// Returns a drawing of the truth tree as a standalone SVG image.
+    ///
+    /// The statements are placed as described in [layout](#method.layout), with the line
+    /// numbers on the left. Each branch is connected to its children by lines, and closed
+    /// and open branches end with a `×` and a `↑`, respectively (or open branches with a `⋮`
+    /// if the truth tree algorithm was [interrupted](#method.interruption)).
+    pub fn to_svg(&self) -> String {
+        let layout = self.layout();
+        let branch_ids = super::branch_ids_left_to_right(self, self.main_trunk_id());
+
+        // Rows right before which some branch splits into its children, which need
+        // some room for the lines to the children
+        let split_rows = branch_ids
+            .iter()
+            .filter(|x| !self.branch_is_last_child(x))
+            .map(|x| layout.branch(x).end_row)
+            .collect::<BTreeSet<_>>();
+
+        let number_width = layout.height().to_string().len();
+
+        let x = |column: usize| MARGIN + (number_width + 2 + column) * CHAR_WIDTH;
+        let y = |row: usize| {
+            MARGIN + (row + split_rows.iter().filter(|x| **x <= row).count()) * ROW_HEIGHT
+        };
+        // Baseline of the text on some row
+        let baseline = |row: usize| y(row) + ROW_HEIGHT * 3 / 4;
+
+        let width = x(layout.width()) + MARGIN;
+        let height = y(layout.height()) + MARGIN;
+
+        let mut svg = String::new();
+
+        writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" \
+             viewBox=\"0 0 {0} {1}\" font-family=\"monospace\" font-size=\"{2}\">",
+            width, height, FONT_SIZE
+        )
+        .unwrap();
+        writeln!(svg, "<rect width=\"100%\" height=\"100%\" fill=\"white\"/>").unwrap();
+
+        let mut numbered_rows = BTreeSet::new();
+
+        for branch_id in &branch_ids {
+            let branch = layout.branch(branch_id);
+            // Characters may not all have the same width, even in a monospace font,
+            // so everything in the branch is centered by the SVG renderer itself
+            let center = x(branch.center) + CHAR_WIDTH / 2;
+
+            for statement_id in self.branch_from_id(branch_id).statement_ids() {
+                let statement = layout.statement(&statement_id);
+
+                writeln!(
+                    svg,
+                    "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+                    center,
+                    baseline(statement.row),
+                    escape(&statement.label)
+                )
+                .unwrap();
+
+                numbered_rows.insert(statement.row);
+            }
+
+            if let Some(marker) = branch.marker {
+                writeln!(
+                    svg,
+                    "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\">{}</text>",
+                    center,
+                    baseline(branch.end_row),
+                    marker
+                )
+                .unwrap();
+            }
+
+            // Lines from the end of the branch to the start of each child
+            for child_branch_id in self.traverse_branch_direct_descendants_ids(branch_id) {
+                let first_statement_id = self
+                    .branch_from_id(&child_branch_id)
+                    .statement_ids()
+                    .next()
+                    .unwrap();
+
+                writeln!(
+                    svg,
+                    "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"black\"/>",
+                    center,
+                    y(branch.end_row) - ROW_HEIGHT,
+                    x(layout.branch(&child_branch_id).center) + CHAR_WIDTH / 2,
+                    y(layout.statement(&first_statement_id).row)
+                )
+                .unwrap();
+            }
+        }
+
+        for row in numbered_rows {
+            writeln!(
+                svg,
+                "<text x=\"{}\" y=\"{}\" text-anchor=\"end\">{}</text>",
+                MARGIN + number_width * CHAR_WIDTH,
+                baseline(row),
+                row + 1
+            )
+            .unwrap();
+        }
+
+        writeln!(svg, "</svg>").unwrap();
+
+        svg
+    }
+}
+
+// Escapes the characters that can't appear as is in the text of an SVG element
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_statements;
+    use crate::validity::TruthTreeMethod;
+
+    #[test]
+    fn truth_tree_to_svg() {
+        let truth_tree = TruthTreeMethod::new(&parse_statements("{(A ∨ B), ~A}")).compute();
+
+        assert_eq!(
+            truth_tree.to_svg(),
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"244\" height=\"144\" viewBox=\"0 0 244 144\" font-family=\"monospace\" font-size=\"16\">
+<rect width=\"100%\" height=\"100%\" fill=\"white\"/>
+<text x=\"137\" y=\"30\" text-anchor=\"middle\">(A ∨ B)</text>
+<text x=\"137\" y=\"54\" text-anchor=\"middle\">~A</text>
+<line x1=\"137\" y1=\"60\" x2=\"77\" y2=\"84\" stroke=\"black\"/>
+<line x1=\"137\" y1=\"60\" x2=\"187\" y2=\"84\" stroke=\"black\"/>
+<text x=\"77\" y=\"102\" text-anchor=\"middle\">A [1, ∨]</text>
+<text x=\"77\" y=\"126\" text-anchor=\"middle\">×</text>
+<text x=\"187\" y=\"102\" text-anchor=\"middle\">B [1, ∨]</text>
+<text x=\"187\" y=\"126\" text-anchor=\"middle\">↑</text>
+<text x=\"22\" y=\"30\" text-anchor=\"end\">1</text>
+<text x=\"22\" y=\"54\" text-anchor=\"end\">2</text>
+<text x=\"22\" y=\"102\" text-anchor=\"end\">3</text>
+</svg>
+"
+        );
+    }
+
+    #[test]
+    fn escapes_text() {
+        assert_eq!(super::escape("(A & B)"), "(A &amp; B)");
+    }
+}
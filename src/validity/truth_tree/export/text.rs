@@ -0,0 +1,143 @@
+use crate::validity::truth_tree::TruthTree;
+
+impl TruthTree {
+    /// Returns a drawing of the truth tree in plain text, e.g. to print it to a terminal.
+    ///
+    /// The statements are placed as described in [layout](#method.layout), with the line
+    /// numbers on the left. Closed branches end with a `×`, and open branches with a `↑`
+    /// (or with a `⋮` if the truth tree algorithm was [interrupted](#method.interruption)).
+    pub fn to_text(&self) -> String {
+        let layout = self.layout();
+        let branch_ids = super::branch_ids_left_to_right(self, self.main_trunk_id());
+
+        // Each line is its line number (if it has any statements) and its contents
+        let mut lines: Vec<(Option<usize>, Vec<char>)> = vec![];
+
+        for row in 0..layout.height() {
+            let mut line = vec![' '; layout.width()];
+            let mut has_statements = false;
+
+            for branch_id in &branch_ids {
+                for statement_id in self.branch_from_id(branch_id).statement_ids() {
+                    let statement = layout.statement(&statement_id);
+
+                    if statement.row == row {
+                        for (i, c) in statement.label.chars().enumerate() {
+                            line[statement.column + i] = c;
+                        }
+
+                        has_statements = true;
+                    }
+                }
+
+                let branch = layout.branch(branch_id);
+
+                if branch.end_row == row {
+                    if let Some(marker) = branch.marker {
+                        line[branch.center] = marker;
+                    }
+                }
+            }
+
+            lines.push((if has_statements { Some(row + 1) } else { None }, line));
+
+            // Branches that end on this row split into their children right below it
+            let splitting_branch_ids = branch_ids
+                .iter()
+                .filter(|x| layout.branch(x).end_row == row + 1 && !self.branch_is_last_child(x))
+                .collect::<Vec<_>>();
+
+            if !splitting_branch_ids.is_empty() {
+                let mut line = vec![' '; layout.width()];
+
+                for branch_id in splitting_branch_ids {
+                    let center = layout.branch(branch_id).center;
+                    let children_centers = self
+                        .traverse_branch_direct_descendants_ids(branch_id)
+                        .map(|x| layout.branch(&x).center)
+                        .collect::<Vec<_>>();
+
+                    let first = children_centers[0];
+                    let last = children_centers[children_centers.len() - 1];
+
+                    for x in &mut line[first..=last] {
+                        *x = '─';
+                    }
+
+                    for &x in &children_centers {
+                        line[x] = '┬';
+                    }
+
+                    line[first] = '┌';
+                    line[last] = '┐';
+                    line[center] = if children_centers.contains(&center) {
+                        '┼'
+                    } else {
+                        '┴'
+                    };
+                }
+
+                lines.push((None, line));
+            }
+        }
+
+        let number_width = layout.height().to_string().len();
+
+        lines
+            .into_iter()
+            .map(|(number, line)| {
+                let number = match number {
+                    Some(number) => number.to_string(),
+                    None => String::new(),
+                };
+
+                format!(
+                    "{:>width$}  {}",
+                    number,
+                    line.into_iter().collect::<String>(),
+                    width = number_width
+                )
+                .trim_end()
+                .to_owned()
+                    + "\n"
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse_statements;
+    use crate::validity::TruthTreeMethod;
+
+    #[test]
+    fn truth_tree_to_text() {
+        let truth_tree = TruthTreeMethod::new(&parse_statements("{(A ∨ B), ~A}")).compute();
+
+        assert_eq!(
+            truth_tree.to_text(),
+            "1        (A ∨ B)
+2          ~A
+      ┌─────┴────┐
+3  A [1, ∨]   B [1, ∨]
+      ×          ↑
+"
+        );
+    }
+
+    #[test]
+    fn statements_from_same_derivation_on_same_line() {
+        let truth_tree = TruthTreeMethod::new(&parse_statements("{(A ≡ B), ~A}")).compute();
+
+        assert_eq!(
+            truth_tree.to_text(),
+            "1        (A ≡ B)
+2           ~A
+      ┌─────┴─────┐
+3  A [1, ≡]   ~A [1, ≡]
+4  B [1, ≡]   ~B [1, ≡]
+      ×           ↑
+"
+        );
+    }
+}
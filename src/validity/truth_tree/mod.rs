@@ -1,15 +1,19 @@
 mod branch;
+mod export;
 mod iter;
 
 #[cfg(feature = "serde_support")]
 mod serde_support;
 
 pub use branch::*;
+pub use export::*;
 pub use iter::*;
 
 #[cfg(feature = "serde_support")]
 pub use serde_support::*;
 
+use super::algorithm::Interruption;
+use super::interpretation::Interpretation;
 use id_tree::InsertBehavior::*;
 use id_tree::*;
 use std::iter::once;
@@ -25,12 +29,14 @@ pub struct TreeId(NodeId);
 /// A truth tree generated by the truth tree algorithm.
 pub struct TruthTree {
     tree: Tree<Branch>,
+    interruption: Option<Interruption>,
 }
 
 impl<'a> TruthTree {
     pub(in crate::validity) fn new(main_branch: Branch) -> Self {
         TruthTree {
             tree: TreeBuilder::new().with_root(Node::new(main_branch)).build(),
+            interruption: None,
         }
     }
 
@@ -164,6 +170,42 @@ impl<'a> TruthTree {
             .count()
             > 0
     }
+
+    pub(in crate::validity) fn interrupt(&mut self, interruption: Interruption) {
+        self.interruption = Some(interruption);
+    }
+
+    /// Returns the reason why the truth tree algorithm stopped before finishing this tree,
+    /// if it did. See [Limits](struct.Limits.html).
+    pub fn interruption(&self) -> Option<&Interruption> {
+        self.interruption.as_ref()
+    }
+
+    /// Returns true if the truth tree algorithm finished this tree, i.e. if every rule
+    /// that could be applied was applied, or every branch closed.
+    pub fn is_complete(&self) -> bool {
+        self.interruption.is_none()
+    }
+
+    /// Returns an interpretation under which every statement on some completed
+    /// open branch `branch_id` (including the initial statements) is true, or `None`
+    /// if the branch is closed or isn't at the end of the tree.
+    ///
+    /// For the truth tree of an argument, this is a counterexample: an interpretation
+    /// under which the premises are true and the conclusion is false.
+    ///
+    /// # Panics
+    /// Panics if the ID provided does not represent a branch from this truth tree.
+    pub fn interpretation_from_branch(&self, branch_id: &TreeId) -> Option<Interpretation> {
+        Interpretation::from_branch(&self, &branch_id)
+    }
+
+    /// Returns the interpretation of the first completed open branch of the tree, or `None`
+    /// if there is none. See [interpretation_from_branch](#method.interpretation_from_branch).
+    pub fn interpretation(&self) -> Option<Interpretation> {
+        self.traverse_downwards_branches_ids(&self.main_trunk_id())
+            .find_map(|x| self.interpretation_from_branch(&x))
+    }
 }
 
 #[cfg(test)]
@@ -368,9 +410,19 @@ mod tests {
 
         assert!(truth_tree.is_open(), "returned not open but tree is open");
 
+        let main_trunk_id = truth_tree.main_trunk_id();
+        let location = BranchNodeLocation {
+            node_id: truth_tree
+                .branch_from_id(&main_trunk_id)
+                .statement_ids()
+                .next()
+                .unwrap(),
+            branch_id: main_trunk_id.clone(),
+        };
+
         truth_tree
-            .branch_from_id_mut(&truth_tree.main_trunk_id())
-            .close();
+            .branch_from_id_mut(&main_trunk_id)
+            .close((location.clone(), location));
 
         assert!(!truth_tree.is_open(), "returned open but tree is not open");
     }
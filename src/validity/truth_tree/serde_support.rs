@@ -9,9 +9,10 @@ impl<'a> Serialize for BranchSer<'a> {
     where
         S: Serializer,
     {
-        let mut branch = serializer.serialize_struct("Branch", 4)?;
+        let mut branch = serializer.serialize_struct("Branch", 5)?;
         branch.serialize_field("id", &self.0)?;
         branch.serialize_field("closed", &self.1.branch_from_id(&self.0).is_closed())?;
+        branch.serialize_field("closed_by", &self.1.branch_from_id(&self.0).closed_by())?;
         branch.serialize_field(
             "nodes",
             &BranchNodesSer(self.1.branch_from_id(&self.0).statements()),
@@ -99,8 +100,9 @@ impl Serialize for TruthTree {
     where
         S: Serializer,
     {
-        let mut tree = serializer.serialize_struct("TruthTree", 1)?;
+        let mut tree = serializer.serialize_struct("TruthTree", 2)?;
         tree.serialize_field("main_trunk", &BranchSer(&self.main_trunk_id(), &self))?;
+        tree.serialize_field("interruption", &self.interruption())?;
         tree.end()
     }
 }
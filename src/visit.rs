@@ -0,0 +1,449 @@
+//! Traversing and rewriting statements without writing out the recursion over the AST.
+//!
+//! [Visit](trait.Visit.html) walks a statement by reference, [VisitMut](trait.VisitMut.html)
+//! walks it by mutable reference, and [Fold](trait.Fold.html) takes it by value and builds a
+//! new one. Each trait has a method per kind of node, which by default calls the function of
+//! the same name in this module to go through the children of that node, so an implementor
+//! only overrides the methods for the nodes it cares about. An overriding method can still
+//! call that function to keep going down the tree.
+//!
+//! ```
+//! use logic_rs::visit::Visit;
+//! use logic_rs::{parse_input, InputKind, SingularTerm};
+//!
+//! // Counts the singular terms of a statement
+//! struct CountSingularTerms(usize);
+//!
+//! impl Visit for CountSingularTerms {
+//!     fn visit_singular_term(&mut self, _: &SingularTerm) {
+//!         self.0 += 1;
+//!     }
+//! }
+//!
+//! match parse_input("(F²ab ⊃ ∃x(x = c))") {
+//!     Ok(InputKind::Statement(st)) => {
+//!         let mut counter = CountSingularTerms(0);
+//!         counter.visit_statement(st.statement());
+//!
+//!         assert_eq!(counter.0, 3);
+//!     }
+//!     _ => assert!(false),
+//! }
+//! ```
+//!
+//! Nodes are visited in the order they appear in when the statement is written out. A
+//! quantifier's variable is visited (with `visit_variable`) before its formula, just as each
+//! variable used as a term is (through `visit_term`).
+
+use crate::parser::{
+    Formula, FunctionSymbol, PredicateLetter, SimpleStatementLetter, SingularTerm, Statement, Term,
+    Variable,
+};
+
+/// Walks a statement by reference. See the [module documentation](index.html).
+pub trait Visit {
+    fn visit_statement(&mut self, statement: &Statement) {
+        visit_statement(self, statement)
+    }
+
+    fn visit_formula(&mut self, formula: &Formula) {
+        visit_formula(self, formula)
+    }
+
+    fn visit_term(&mut self, term: &Term) {
+        visit_term(self, term)
+    }
+
+    fn visit_simple_statement_letter(&mut self, _letter: &SimpleStatementLetter) {}
+
+    fn visit_predicate_letter(&mut self, _predicate_letter: &PredicateLetter) {}
+
+    fn visit_singular_term(&mut self, _singular_term: &SingularTerm) {}
+
+    fn visit_variable(&mut self, _variable: &Variable) {}
+
+    fn visit_function_symbol(&mut self, _function_symbol: &FunctionSymbol) {}
+}
+
+/// Walks a statement by mutable reference. See the [module documentation](index.html).
+pub trait VisitMut {
+    fn visit_statement_mut(&mut self, statement: &mut Statement) {
+        visit_statement_mut(self, statement)
+    }
+
+    fn visit_formula_mut(&mut self, formula: &mut Formula) {
+        visit_formula_mut(self, formula)
+    }
+
+    fn visit_term_mut(&mut self, term: &mut Term) {
+        visit_term_mut(self, term)
+    }
+
+    fn visit_simple_statement_letter_mut(&mut self, _letter: &mut SimpleStatementLetter) {}
+
+    fn visit_predicate_letter_mut(&mut self, _predicate_letter: &mut PredicateLetter) {}
+
+    fn visit_singular_term_mut(&mut self, _singular_term: &mut SingularTerm) {}
+
+    fn visit_variable_mut(&mut self, _variable: &mut Variable) {}
+
+    fn visit_function_symbol_mut(&mut self, _function_symbol: &mut FunctionSymbol) {}
+}
+
+/// Takes a statement by value and builds a new one out of it.
+/// See the [module documentation](index.html).
+pub trait Fold {
+    fn fold_statement(&mut self, statement: Statement) -> Statement {
+        fold_statement(self, statement)
+    }
+
+    fn fold_formula(&mut self, formula: Formula) -> Formula {
+        fold_formula(self, formula)
+    }
+
+    fn fold_term(&mut self, term: Term) -> Term {
+        fold_term(self, term)
+    }
+
+    fn fold_simple_statement_letter(
+        &mut self,
+        letter: SimpleStatementLetter,
+    ) -> SimpleStatementLetter {
+        letter
+    }
+
+    fn fold_predicate_letter(&mut self, predicate_letter: PredicateLetter) -> PredicateLetter {
+        predicate_letter
+    }
+
+    fn fold_singular_term(&mut self, singular_term: SingularTerm) -> SingularTerm {
+        singular_term
+    }
+
+    fn fold_variable(&mut self, variable: Variable) -> Variable {
+        variable
+    }
+
+    fn fold_function_symbol(&mut self, function_symbol: FunctionSymbol) -> FunctionSymbol {
+        function_symbol
+    }
+}
+
+/// Visits the children of `statement`.
+pub fn visit_statement<V: Visit + ?Sized>(visitor: &mut V, statement: &Statement) {
+    match statement {
+        Statement::Simple(letter) => visitor.visit_simple_statement_letter(letter),
+        Statement::Singular(predicate_letter, terms) => {
+            visitor.visit_predicate_letter(predicate_letter);
+
+            for term in terms {
+                visitor.visit_singular_term(term);
+            }
+        }
+        Statement::Identity(lterm, rterm) => {
+            visitor.visit_singular_term(lterm);
+            visitor.visit_singular_term(rterm);
+        }
+        Statement::LogicalNegation(rstatement) => visitor.visit_statement(rstatement),
+        Statement::LogicalConjunction(lstatement, rstatement)
+        | Statement::LogicalDisjunction(lstatement, rstatement)
+        | Statement::LogicalConditional(lstatement, rstatement)
+        | Statement::LogicalBiconditional(lstatement, rstatement) => {
+            visitor.visit_statement(lstatement);
+            visitor.visit_statement(rstatement);
+        }
+        Statement::Existential(variable, formula) | Statement::Universal(variable, formula) => {
+            visitor.visit_variable(variable);
+            visitor.visit_formula(formula);
+        }
+    }
+}
+
+/// Visits the children of `formula`.
+pub fn visit_formula<V: Visit + ?Sized>(visitor: &mut V, formula: &Formula) {
+    match formula {
+        Formula::Statement(statement) => visitor.visit_statement(statement),
+        Formula::Predicate(predicate_letter, terms) => {
+            visitor.visit_predicate_letter(predicate_letter);
+
+            for term in terms {
+                visitor.visit_term(term);
+            }
+        }
+        Formula::Identity(lterm, rterm) => {
+            visitor.visit_term(lterm);
+            visitor.visit_term(rterm);
+        }
+        Formula::Negation(rformula) => visitor.visit_formula(rformula),
+        Formula::Conjunction(lformula, rformula)
+        | Formula::Disjunction(lformula, rformula)
+        | Formula::Conditional(lformula, rformula)
+        | Formula::Biconditional(lformula, rformula) => {
+            visitor.visit_formula(lformula);
+            visitor.visit_formula(rformula);
+        }
+    }
+}
+
+/// Visits the singular term or variable that `term` is, or the function symbol
+/// and terms it is made of.
+pub fn visit_term<V: Visit + ?Sized>(visitor: &mut V, term: &Term) {
+    match term {
+        Term::SingularTerm(singular_term) => visitor.visit_singular_term(singular_term),
+        Term::Variable(variable) => visitor.visit_variable(variable),
+        Term::Function(function_symbol, terms) => {
+            visitor.visit_function_symbol(function_symbol);
+
+            for term in terms {
+                visitor.visit_term(term);
+            }
+        }
+    }
+}
+
+/// Visits the children of `statement`.
+pub fn visit_statement_mut<V: VisitMut + ?Sized>(visitor: &mut V, statement: &mut Statement) {
+    match statement {
+        Statement::Simple(letter) => visitor.visit_simple_statement_letter_mut(letter),
+        Statement::Singular(predicate_letter, terms) => {
+            visitor.visit_predicate_letter_mut(predicate_letter);
+
+            for term in terms {
+                visitor.visit_singular_term_mut(term);
+            }
+        }
+        Statement::Identity(lterm, rterm) => {
+            visitor.visit_singular_term_mut(lterm);
+            visitor.visit_singular_term_mut(rterm);
+        }
+        Statement::LogicalNegation(rstatement) => visitor.visit_statement_mut(rstatement),
+        Statement::LogicalConjunction(lstatement, rstatement)
+        | Statement::LogicalDisjunction(lstatement, rstatement)
+        | Statement::LogicalConditional(lstatement, rstatement)
+        | Statement::LogicalBiconditional(lstatement, rstatement) => {
+            visitor.visit_statement_mut(lstatement);
+            visitor.visit_statement_mut(rstatement);
+        }
+        Statement::Existential(variable, formula) | Statement::Universal(variable, formula) => {
+            visitor.visit_variable_mut(variable);
+            visitor.visit_formula_mut(formula);
+        }
+    }
+}
+
+/// Visits the children of `formula`.
+pub fn visit_formula_mut<V: VisitMut + ?Sized>(visitor: &mut V, formula: &mut Formula) {
+    match formula {
+        Formula::Statement(statement) => visitor.visit_statement_mut(statement),
+        Formula::Predicate(predicate_letter, terms) => {
+            visitor.visit_predicate_letter_mut(predicate_letter);
+
+            for term in terms {
+                visitor.visit_term_mut(term);
+            }
+        }
+        Formula::Identity(lterm, rterm) => {
+            visitor.visit_term_mut(lterm);
+            visitor.visit_term_mut(rterm);
+        }
+        Formula::Negation(rformula) => visitor.visit_formula_mut(rformula),
+        Formula::Conjunction(lformula, rformula)
+        | Formula::Disjunction(lformula, rformula)
+        | Formula::Conditional(lformula, rformula)
+        | Formula::Biconditional(lformula, rformula) => {
+            visitor.visit_formula_mut(lformula);
+            visitor.visit_formula_mut(rformula);
+        }
+    }
+}
+
+/// Visits the singular term or variable that `term` is, or the function symbol
+/// and terms it is made of.
+pub fn visit_term_mut<V: VisitMut + ?Sized>(visitor: &mut V, term: &mut Term) {
+    match term {
+        Term::SingularTerm(singular_term) => visitor.visit_singular_term_mut(singular_term),
+        Term::Variable(variable) => visitor.visit_variable_mut(variable),
+        Term::Function(function_symbol, terms) => {
+            visitor.visit_function_symbol_mut(function_symbol);
+
+            for term in terms {
+                visitor.visit_term_mut(term);
+            }
+        }
+    }
+}
+
+/// Folds the children of `statement`, and rebuilds it out of the results.
+pub fn fold_statement<F: Fold + ?Sized>(folder: &mut F, statement: Statement) -> Statement {
+    match statement {
+        Statement::Simple(letter) => Statement::Simple(folder.fold_simple_statement_letter(letter)),
+        Statement::Singular(predicate_letter, terms) => Statement::Singular(
+            folder.fold_predicate_letter(predicate_letter),
+            terms
+                .into_iter()
+                .map(|x| folder.fold_singular_term(x))
+                .collect(),
+        ),
+        Statement::Identity(lterm, rterm) => Statement::Identity(
+            folder.fold_singular_term(lterm),
+            folder.fold_singular_term(rterm),
+        ),
+        Statement::LogicalNegation(rstatement) => {
+            Statement::LogicalNegation(Box::new(folder.fold_statement(*rstatement)))
+        }
+        Statement::LogicalConjunction(lstatement, rstatement) => Statement::LogicalConjunction(
+            Box::new(folder.fold_statement(*lstatement)),
+            Box::new(folder.fold_statement(*rstatement)),
+        ),
+        Statement::LogicalDisjunction(lstatement, rstatement) => Statement::LogicalDisjunction(
+            Box::new(folder.fold_statement(*lstatement)),
+            Box::new(folder.fold_statement(*rstatement)),
+        ),
+        Statement::LogicalConditional(lstatement, rstatement) => Statement::LogicalConditional(
+            Box::new(folder.fold_statement(*lstatement)),
+            Box::new(folder.fold_statement(*rstatement)),
+        ),
+        Statement::LogicalBiconditional(lstatement, rstatement) => Statement::LogicalBiconditional(
+            Box::new(folder.fold_statement(*lstatement)),
+            Box::new(folder.fold_statement(*rstatement)),
+        ),
+        Statement::Existential(variable, formula) => Statement::Existential(
+            folder.fold_variable(variable),
+            Box::new(folder.fold_formula(*formula)),
+        ),
+        Statement::Universal(variable, formula) => Statement::Universal(
+            folder.fold_variable(variable),
+            Box::new(folder.fold_formula(*formula)),
+        ),
+    }
+}
+
+/// Folds the children of `formula`, and rebuilds it out of the results.
+pub fn fold_formula<F: Fold + ?Sized>(folder: &mut F, formula: Formula) -> Formula {
+    match formula {
+        Formula::Statement(statement) => {
+            Formula::Statement(Box::new(folder.fold_statement(*statement)))
+        }
+        Formula::Predicate(predicate_letter, terms) => Formula::Predicate(
+            folder.fold_predicate_letter(predicate_letter),
+            terms.into_iter().map(|x| folder.fold_term(x)).collect(),
+        ),
+        Formula::Identity(lterm, rterm) => {
+            Formula::Identity(folder.fold_term(lterm), folder.fold_term(rterm))
+        }
+        Formula::Negation(rformula) => Formula::Negation(Box::new(folder.fold_formula(*rformula))),
+        Formula::Conjunction(lformula, rformula) => Formula::Conjunction(
+            Box::new(folder.fold_formula(*lformula)),
+            Box::new(folder.fold_formula(*rformula)),
+        ),
+        Formula::Disjunction(lformula, rformula) => Formula::Disjunction(
+            Box::new(folder.fold_formula(*lformula)),
+            Box::new(folder.fold_formula(*rformula)),
+        ),
+        Formula::Conditional(lformula, rformula) => Formula::Conditional(
+            Box::new(folder.fold_formula(*lformula)),
+            Box::new(folder.fold_formula(*rformula)),
+        ),
+        Formula::Biconditional(lformula, rformula) => Formula::Biconditional(
+            Box::new(folder.fold_formula(*lformula)),
+            Box::new(folder.fold_formula(*rformula)),
+        ),
+    }
+}
+
+/// Folds the singular term or variable that `term` is, or the function symbol
+/// and terms it is made of.
+pub fn fold_term<F: Fold + ?Sized>(folder: &mut F, term: Term) -> Term {
+    match term {
+        Term::SingularTerm(singular_term) => {
+            Term::SingularTerm(folder.fold_singular_term(singular_term))
+        }
+        Term::Variable(variable) => Term::Variable(folder.fold_variable(variable)),
+        Term::Function(function_symbol, terms) => Term::Function(
+            folder.fold_function_symbol(function_symbol),
+            terms.into_iter().map(|x| folder.fold_term(x)).collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::{parse_statement, Subscript};
+
+    #[test]
+    fn visits_in_order() {
+        #[derive(Default)]
+        struct Collect(Vec<String>);
+
+        impl Visit for Collect {
+            fn visit_simple_statement_letter(&mut self, letter: &SimpleStatementLetter) {
+                self.0.push(letter.to_string());
+            }
+
+            fn visit_predicate_letter(&mut self, predicate_letter: &PredicateLetter) {
+                self.0.push(predicate_letter.to_string());
+            }
+
+            fn visit_singular_term(&mut self, singular_term: &SingularTerm) {
+                self.0.push(singular_term.to_string());
+            }
+
+            fn visit_variable(&mut self, variable: &Variable) {
+                self.0.push(variable.to_string());
+            }
+        }
+
+        let mut collect = Collect::default();
+        collect.visit_statement(&parse_statement("(A ⊃ ∀x(F²xa ∨ x = b))"));
+
+        assert_eq!(collect.0, vec!["A", "x", "F²", "x", "a", "x", "b"]);
+    }
+
+    #[test]
+    fn visits_mutably() {
+        struct Rename;
+
+        impl VisitMut for Rename {
+            fn visit_variable_mut(&mut self, variable: &mut Variable) {
+                variable.0 = 'y';
+            }
+
+            fn visit_singular_term_mut(&mut self, singular_term: &mut SingularTerm) {
+                singular_term.1 = Subscript(Some(1));
+            }
+        }
+
+        let mut statement = parse_statement("(F¹a & ∃x(x = b))");
+        Rename.visit_statement_mut(&mut statement);
+
+        assert_eq!(statement, parse_statement("(F¹a₁ & ∃y(y = b₁))"));
+    }
+
+    #[test]
+    fn folds() {
+        // Negates every predicate
+        struct NegatePredicates;
+
+        impl Fold for NegatePredicates {
+            fn fold_statement(&mut self, statement: Statement) -> Statement {
+                match statement {
+                    Statement::Singular(_, _) => Statement::LogicalNegation(Box::new(statement)),
+                    statement => fold_statement(self, statement),
+                }
+            }
+
+            fn fold_formula(&mut self, formula: Formula) -> Formula {
+                match formula {
+                    Formula::Predicate(_, _) => Formula::Negation(Box::new(formula)),
+                    formula => fold_formula(self, formula),
+                }
+            }
+        }
+
+        assert_eq!(
+            NegatePredicates.fold_statement(parse_statement("(F¹a ∨ ∀x(G¹x ⊃ A))")),
+            parse_statement("(~F¹a ∨ ∀x(~G¹x ⊃ A))")
+        );
+    }
+}